@@ -0,0 +1,118 @@
+//! Measures encoding and decoding throughput and heap allocations.
+//!
+//! Run with `cargo bench --bench encode`, adding `--features rayon` to also measure
+//! how the parallel functions scale with the number of threads.
+
+use base24::Base24;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+const BATCH: u32 = 100;
+
+/// Runs `f` repeatedly for about a second and reports the time and allocations per run.
+fn bench<T>(name: &str, bytes: usize, mut f: impl FnMut() -> T) {
+    let mut runs = 0u32;
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let start = Instant::now();
+
+    // Check the clock only once per batch, as it is slower than the smallest inputs
+    while start.elapsed() < Duration::from_secs(1) {
+        for _ in 0..BATCH {
+            black_box(f());
+        }
+
+        runs += BATCH;
+    }
+
+    let per_run = start.elapsed() / runs;
+    let allocations = (ALLOCATIONS.load(Ordering::Relaxed) - allocations) / runs as usize;
+
+    println!(
+        "{:<24} {:>12?}/run {:>10.1} MiB/s {:>6} allocations/run",
+        name,
+        per_run,
+        bytes as f64 / per_run.as_secs_f64() / (1024.0 * 1024.0),
+        allocations
+    );
+}
+
+fn main() {
+    let base24 = Base24::new();
+
+    for &(label, len) in &[("16 B", 16), ("1 KiB", 1024), ("1 MiB", 1024 * 1024)] {
+        let data: Vec<u8> = (0..len).map(|idx| (idx * 7 % 251) as u8).collect();
+        let encoded = base24.encode(&data).expect("data is aligned");
+
+        bench(&format!("encode {}", label), len, || {
+            base24.encode(black_box(&data))
+        });
+        bench(&format!("decode {}", label), len, || {
+            base24.decode(black_box(&encoded))
+        });
+    }
+
+    bench("encode_group", 4, || {
+        base24.encode_group(black_box([0x88, 0x55, 0x33, 0x11]))
+    });
+    bench("decode_group", 4, || {
+        base24.decode_group(black_box("5YEATXA"))
+    });
+
+    #[cfg(feature = "rayon")]
+    bench_par(&base24);
+}
+
+/// Measures the parallel functions on 4 MiB with 1, 2, 4, ... threads up to the number of
+/// cores.
+#[cfg(feature = "rayon")]
+fn bench_par(base24: &Base24) {
+    let len = 4 * 1024 * 1024;
+    let data: Vec<u8> = (0..len).map(|idx| (idx * 7 % 251) as u8).collect();
+    let encoded = base24.encode(&data).expect("data is aligned");
+
+    let cores = std::thread::available_parallelism().map_or(1, |cores| cores.get());
+    let mut threads = 1;
+
+    while threads <= cores {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .expect("the thread pool can be built");
+
+        pool.install(|| {
+            bench(&format!("par_encode 4 MiB x{}", threads), len, || {
+                base24.par_encode(black_box(&data))
+            });
+            bench(&format!("par_decode 4 MiB x{}", threads), len, || {
+                base24.par_decode(black_box(&encoded))
+            });
+        });
+
+        threads *= 2;
+    }
+}
@@ -0,0 +1,48 @@
+//! Generates the lookup tables for the default alphabet, so that `Base24::new` does not
+//! have to build them at runtime.
+
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+/// Must match `ALPHABET` in `src/lib.rs`.
+const ALPHABET: &str = "ZAC2B3EF4GH5TK67P8RS9WXY";
+
+/// Must match `INVALID` in `src/lib.rs`.
+const INVALID: u8 = u8::MAX;
+
+fn main() {
+    let mut decode_map = [INVALID; 256];
+
+    for (idx, byte) in ALPHABET.bytes().enumerate() {
+        decode_map[usize::from(byte)] = idx as u8;
+    }
+
+    for (idx, byte) in ALPHABET.bytes().enumerate() {
+        let lower = usize::from(byte.to_ascii_lowercase());
+
+        if decode_map[lower] == INVALID {
+            decode_map[lower] = idx as u8;
+        }
+    }
+
+    let mut tables = String::new();
+    writeln!(
+        tables,
+        "const DEFAULT_ENCODE_MAP: [u8; 24] = {:?};",
+        ALPHABET.as_bytes()
+    )
+    .unwrap();
+    writeln!(
+        tables,
+        "const DEFAULT_DECODE_MAP: [u8; 256] = {:?};",
+        decode_map
+    )
+    .unwrap();
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR is set by cargo");
+    fs::write(Path::new(&out_dir).join("tables.rs"), tables).expect("failed to write tables");
+
+    println!("cargo:rerun-if-changed=build.rs");
+}
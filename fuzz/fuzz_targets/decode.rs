@@ -0,0 +1,17 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    // Decoding must never panic, and whatever decodes must encode back to the input
+    if let Ok(decoded) = base24::decode_bytes(data) {
+        let encoded = base24::encode(&decoded).expect("decoded data is aligned");
+
+        assert!(encoded.as_bytes().eq_ignore_ascii_case(data));
+    }
+
+    if let Ok(text) = std::str::from_utf8(data) {
+        let _ = base24::decode(text);
+        let _ = base24::decode_lenient(text);
+    }
+});
@@ -0,0 +1,289 @@
+//! Asynchronous counterparts of the [`stream`](crate::stream) adapters for tokio.
+
+use crate::errors::Base24Error;
+use crate::stream::GroupBuffer;
+use crate::{Base24, BYTES_PER_GROUP};
+use std::io;
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+
+/// Streaming encoder wrapping an [`AsyncWrite`] sink, like
+/// [`Base24Writer`](crate::stream::Base24Writer).
+///
+/// Written data is accepted as soon as the text encoded from the previous write was
+/// passed on to the inner writer. Shutting down fails if the total number of bytes
+/// written is not a multiple of 4.
+pub struct Base24AsyncWriter<W: AsyncWrite + Unpin> {
+    base24: Base24,
+    inner: W,
+    pending: Vec<u8>,
+    encoded: String,
+    sent: usize,
+    written: usize,
+}
+
+impl<W: AsyncWrite + Unpin> Base24AsyncWriter<W> {
+    pub fn new(inner: W) -> Base24AsyncWriter<W> {
+        Base24AsyncWriter::with_base24(Base24::new(), inner)
+    }
+
+    pub fn with_base24(base24: Base24, inner: W) -> Base24AsyncWriter<W> {
+        Base24AsyncWriter {
+            base24,
+            inner,
+            pending: Vec::with_capacity(BYTES_PER_GROUP),
+            encoded: String::new(),
+            sent: 0,
+            written: 0,
+        }
+    }
+
+    /// Returns the inner writer, dropping any text that was not passed on to it yet.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    /// Passes the encoded text on to the inner writer.
+    fn poll_send(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        while self.sent < self.encoded.len() {
+            let len = ready!(
+                Pin::new(&mut self.inner).poll_write(cx, &self.encoded.as_bytes()[self.sent..])
+            )?;
+
+            if len == 0 {
+                return Poll::Ready(Err(io::ErrorKind::WriteZero.into()));
+            }
+
+            self.sent += len;
+        }
+
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<W: AsyncWrite + Unpin> AsyncWrite for Base24AsyncWriter<W> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+
+        ready!(this.poll_send(cx))?;
+
+        this.pending.extend_from_slice(buf);
+        this.written += buf.len();
+
+        let complete = this.pending.len() - this.pending.len() % BYTES_PER_GROUP;
+        this.base24
+            .encode_into(&this.pending[..complete], &mut this.encoded)
+            .unwrap_or_else(|_| {
+                unreachable!("Only complete groups are encoded. Something is wrong!")
+            });
+        this.pending.drain(..complete);
+        this.sent = 0;
+
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+
+        ready!(this.poll_send(cx))?;
+
+        Pin::new(&mut this.inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+
+        if !this.pending.is_empty() {
+            return Poll::Ready(Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                Base24Error::EncodeInputLengthInvalid { len: this.written },
+            )));
+        }
+
+        ready!(this.poll_send(cx))?;
+
+        Pin::new(&mut this.inner).poll_shutdown(cx)
+    }
+}
+
+/// Streaming decoder wrapping an [`AsyncRead`] source of base24 text, like
+/// [`Base24Reader`](crate::stream::Base24Reader).
+///
+/// Invalid characters and a trailing partial group at the end of the input surface as
+/// [`io::ErrorKind::InvalidData`] errors.
+pub struct Base24AsyncReader<R: AsyncRead + Unpin> {
+    base24: Base24,
+    inner: R,
+    groups: GroupBuffer,
+    decoded: Vec<u8>,
+    position: usize,
+}
+
+impl<R: AsyncRead + Unpin> Base24AsyncReader<R> {
+    pub fn new(inner: R) -> Base24AsyncReader<R> {
+        Base24AsyncReader::with_base24(Base24::new(), inner)
+    }
+
+    pub fn with_base24(base24: Base24, inner: R) -> Base24AsyncReader<R> {
+        Base24AsyncReader {
+            base24,
+            inner,
+            groups: GroupBuffer::new(),
+            decoded: Vec::new(),
+            position: 0,
+        }
+    }
+
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: AsyncRead + Unpin> AsyncRead for Base24AsyncReader<R> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        let mut input = [0u8; 1024];
+
+        if buf.remaining() == 0 {
+            return Poll::Ready(Ok(()));
+        }
+
+        while this.position == this.decoded.len() {
+            let mut input = ReadBuf::new(&mut input);
+
+            ready!(Pin::new(&mut this.inner).poll_read(cx, &mut input))?;
+
+            if input.filled().is_empty() {
+                return Poll::Ready(this.groups.finish());
+            }
+
+            this.groups
+                .decode(&this.base24, input.filled(), &mut this.decoded)?;
+            this.position = 0;
+        }
+
+        let len = buf.remaining().min(this.decoded.len() - this.position);
+        buf.put_slice(&this.decoded[this.position..this.position + len]);
+        this.position += len;
+
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    #[tokio::test]
+    async fn test_async_writer() {
+        let data: Vec<u8> = (0..=255).collect();
+
+        let mut writer = Base24AsyncWriter::new(Vec::new());
+        for piece in data.chunks(3) {
+            writer
+                .write_all(piece)
+                .await
+                .expect("error during test write");
+        }
+        writer.shutdown().await.expect("error during test shutdown");
+
+        assert_eq!(
+            writer.into_inner(),
+            crate::encode(&data)
+                .expect("error during test encode")
+                .into_bytes()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_async_writer_partial_group() {
+        let mut writer = Base24AsyncWriter::new(Vec::new());
+        writer
+            .write_all(&[1, 2, 3, 4, 5])
+            .await
+            .expect("error during test write");
+
+        let err = writer
+            .shutdown()
+            .await
+            .expect_err("partial group must fail");
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(
+            err.into_inner()
+                .and_then(|err| err.downcast::<Base24Error>().ok())
+                .map(|err| *err),
+            Some(Base24Error::EncodeInputLengthInvalid { len: 5 })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_async_reader() {
+        let data: Vec<u8> = (0..=255).collect();
+        let encoded = crate::encode(&data).expect("error during test encode");
+
+        let mut decoded = Vec::new();
+        Base24AsyncReader::new(encoded.as_bytes())
+            .read_to_end(&mut decoded)
+            .await
+            .expect("error during test read");
+
+        assert_eq!(decoded, data);
+
+        let mut reader = Base24AsyncReader::new(&b"x5ggbh75YEATXA"[..]);
+        let mut decoded = Vec::new();
+        let mut byte = [0u8; 1];
+
+        while reader
+            .read(&mut byte)
+            .await
+            .expect("error during test read")
+            == 1
+        {
+            decoded.push(byte[0]);
+        }
+
+        assert_eq!(decoded, [0xFF, 0xFF, 0xFF, 0xFF, 0x88, 0x55, 0x33, 0x11]);
+    }
+
+    #[tokio::test]
+    async fn test_async_reader_failures() {
+        let mut decoded = Vec::new();
+
+        let err = Base24AsyncReader::new(&b"ZZZZZZO"[..])
+            .read_to_end(&mut decoded)
+            .await
+            .expect_err("invalid character must fail");
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(
+            err.into_inner()
+                .and_then(|err| err.downcast::<Base24Error>().ok())
+                .map(|err| *err),
+            Some(Base24Error::DecodeUnsupportedCharacter {
+                character: 'O',
+                position: 6
+            })
+        );
+
+        let err = Base24AsyncReader::new(&b"ZZZZZZAZZ"[..])
+            .read_to_end(&mut decoded)
+            .await
+            .expect_err("partial group must fail");
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(
+            err.into_inner()
+                .and_then(|err| err.downcast::<Base24Error>().ok())
+                .map(|err| *err),
+            Some(Base24Error::TrailingPartialGroup { chars: 2 })
+        );
+    }
+}
@@ -0,0 +1,145 @@
+use crate::errors::Base24Error;
+use crate::{Base24, Case, Endianness, OnOverflow, ALPHABET};
+
+/// Builder for [`Base24`] instances combining several configuration options.
+///
+/// ```
+/// use base24::builder::Base24Builder;
+/// use base24::Case;
+///
+/// let base24 = Base24Builder::new()
+///     .output_case(Case::Lower)
+///     .aliases(&[('0', 'Z')])
+///     .build()?;
+///
+/// assert_eq!(base24.decode_u32("000000A")?, 1);
+/// # Ok::<(), base24::errors::Base24Error>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct Base24Builder<'a> {
+    alphabet: &'a str,
+    endianness: Endianness,
+    on_overflow: OnOverflow,
+    case: Case,
+    aliases: &'a [(char, char)],
+}
+
+impl<'a> Base24Builder<'a> {
+    /// Creates a builder with the same configuration as [`Base24::new`].
+    pub fn new() -> Base24Builder<'a> {
+        Base24Builder {
+            alphabet: ALPHABET,
+            endianness: Endianness::default(),
+            on_overflow: OnOverflow::default(),
+            case: Case::default(),
+            aliases: &[],
+        }
+    }
+
+    /// Sets the alphabet, see [`Base24::with_alphabet`].
+    pub fn alphabet(mut self, alphabet: &'a str) -> Base24Builder<'a> {
+        self.alphabet = alphabet;
+        self
+    }
+
+    /// Sets the byte order, see [`Base24::with_endianness`].
+    pub fn endianness(mut self, endianness: Endianness) -> Base24Builder<'a> {
+        self.endianness = endianness;
+        self
+    }
+
+    /// Sets how groups that overflow 32 bits are decoded, see [`OnOverflow`].
+    pub fn on_overflow(mut self, on_overflow: OnOverflow) -> Base24Builder<'a> {
+        self.on_overflow = on_overflow;
+        self
+    }
+
+    /// Sets the case of the encoded output.
+    ///
+    /// The output case is the canonical form, so [`Base24::normalize`] converts to it and
+    /// [`Base24::decode_strict`] only accepts it.
+    pub fn output_case(mut self, case: Case) -> Base24Builder<'a> {
+        self.case = case;
+        self
+    }
+
+    /// Sets the aliases, see [`Base24::with_aliases`].
+    pub fn aliases(mut self, aliases: &'a [(char, char)]) -> Base24Builder<'a> {
+        self.aliases = aliases;
+        self
+    }
+
+    /// Builds the instance, failing if the options do not fit together.
+    ///
+    /// Besides the checks of the individual options, [`Case::Lower`] requires the
+    /// lowercase form of every alphabet character to decode like the character itself.
+    pub fn build(&self) -> Result<Base24, Base24Error> {
+        let mut base24 = Base24::with_alphabet(self.alphabet)?;
+        base24.endianness = self.endianness;
+        base24.on_overflow = self.on_overflow;
+
+        if self.case == Case::Lower {
+            base24.encode_map = base24.lowercase_map()?;
+        }
+
+        base24.add_aliases(self.aliases)?;
+
+        Ok(base24)
+    }
+}
+
+impl Default for Base24Builder<'_> {
+    fn default() -> Self {
+        Base24Builder::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "alloc")]
+    use alloc::{string::String, vec};
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_builder() {
+        let base24 = Base24Builder::new().build().expect("valid configuration");
+        assert_eq!(base24.alphabet(), ALPHABET);
+        assert_eq!(base24.encode_u32(0x88553311), "5YEATXA");
+
+        let base24 = Base24Builder::new()
+            .alphabet("0123456789ABCDEFGHJKMNPQ")
+            .endianness(Endianness::Little)
+            .output_case(Case::Lower)
+            .aliases(&[('I', '1'), ('O', '0')])
+            .build()
+            .expect("valid configuration");
+
+        assert_eq!(base24.alphabet(), "0123456789abcdefghjkmnpq");
+        assert_eq!(base24.encode(&[23, 0, 0, 0]), Ok(String::from("000000q")));
+        assert_eq!(base24.decode("OOOOOOQ"), Ok(vec![23, 0, 0, 0]));
+        assert_eq!(base24.decode("I000000"), base24.decode("1000000"));
+        assert_eq!(base24.normalize("000000Q"), "000000q");
+        assert!(base24.decode_strict("000000Q").is_err());
+    }
+
+    #[test]
+    fn test_builder_errors() {
+        assert_eq!(
+            Base24Builder::new().alphabet("ZAC").build().err(),
+            Some(Base24Error::InvalidAlphabet)
+        );
+        assert_eq!(
+            Base24Builder::new()
+                .alphabet("ZAC2B3EF4GH5TK67P8RS9WXz")
+                .output_case(Case::Lower)
+                .build()
+                .err(),
+            Some(Base24Error::InvalidAlphabet)
+        );
+        assert_eq!(
+            Base24Builder::new().aliases(&[('Z', 'A')]).build().err(),
+            Some(Base24Error::InvalidAlias('Z'))
+        );
+    }
+}
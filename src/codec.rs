@@ -0,0 +1,180 @@
+//! A [`tokio_util::codec`] encoder and decoder, for use with `Framed` streams.
+
+use crate::errors::Base24Error;
+use crate::stream::GroupBuffer;
+use crate::{encoded_len, Base24, BYTES_PER_GROUP};
+use bytes::BytesMut;
+use std::io;
+use tokio_util::codec::{Decoder, Encoder};
+
+/// Encodes byte slices to base24 text and decodes base24 text to bytes.
+///
+/// Incomplete groups are kept in the codec until the next call. The encoder has no end
+/// of stream, so [`Base24Codec::finish`] reports a trailing partial group of encoded
+/// bytes. The decoder reports one with [`Decoder::decode_eof`].
+pub struct Base24Codec {
+    base24: Base24,
+    pending: Vec<u8>,
+    written: usize,
+    groups: GroupBuffer,
+}
+
+impl Base24Codec {
+    pub fn new() -> Base24Codec {
+        Base24Codec::with_base24(Base24::new())
+    }
+
+    pub fn with_base24(base24: Base24) -> Base24Codec {
+        Base24Codec {
+            base24,
+            pending: Vec::with_capacity(BYTES_PER_GROUP),
+            written: 0,
+            groups: GroupBuffer::new(),
+        }
+    }
+
+    /// Fails if the total number of bytes encoded is not a multiple of 4.
+    pub fn finish(&self) -> io::Result<()> {
+        if self.pending.is_empty() {
+            Ok(())
+        } else {
+            Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                Base24Error::EncodeInputLengthInvalid { len: self.written },
+            ))
+        }
+    }
+}
+
+impl Default for Base24Codec {
+    fn default() -> Base24Codec {
+        Base24Codec::new()
+    }
+}
+
+impl Encoder<&[u8]> for Base24Codec {
+    type Error = io::Error;
+
+    fn encode(&mut self, item: &[u8], dst: &mut BytesMut) -> io::Result<()> {
+        self.pending.extend_from_slice(item);
+        self.written += item.len();
+
+        let complete = self.pending.len() - self.pending.len() % BYTES_PER_GROUP;
+        let len = encoded_len(complete).unwrap_or_else(|| {
+            unreachable!("Only complete groups are encoded. Something is wrong!")
+        });
+
+        let start = dst.len();
+        dst.resize(start + len, 0);
+        self.base24
+            .encode_slice(&self.pending[..complete], &mut dst[start..])
+            .unwrap_or_else(|_| {
+                unreachable!("Only complete groups are encoded. Something is wrong!")
+            });
+        self.pending.drain(..complete);
+
+        Ok(())
+    }
+}
+
+impl Decoder for Base24Codec {
+    type Item = Vec<u8>;
+    type Error = io::Error;
+
+    /// Decodes all complete groups, returning `None` until at least one is available.
+    fn decode(&mut self, src: &mut BytesMut) -> io::Result<Option<Vec<u8>>> {
+        let mut decoded = Vec::new();
+        self.groups
+            .decode(&self.base24, &src.split(), &mut decoded)?;
+
+        if decoded.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(decoded))
+        }
+    }
+
+    fn decode_eof(&mut self, src: &mut BytesMut) -> io::Result<Option<Vec<u8>>> {
+        match self.decode(src)? {
+            Some(decoded) => Ok(Some(decoded)),
+            None => self.groups.finish().map(|()| None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_codec_encode() {
+        let data: Vec<u8> = (0..=255).collect();
+
+        let mut codec = Base24Codec::new();
+        let mut encoded = BytesMut::new();
+        for piece in data.chunks(3) {
+            codec
+                .encode(piece, &mut encoded)
+                .expect("error during test encode");
+        }
+        codec.finish().expect("error during test finish");
+
+        assert_eq!(
+            encoded,
+            crate::encode(&data).expect("error during test encode")
+        );
+
+        codec
+            .encode(&[1, 2, 3, 4, 5][..], &mut encoded)
+            .expect("error during test encode");
+        let err = codec.finish().expect_err("partial group must fail");
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(
+            err.into_inner()
+                .and_then(|err| err.downcast::<Base24Error>().ok())
+                .map(|err| *err),
+            Some(Base24Error::EncodeInputLengthInvalid { len: 261 })
+        );
+    }
+
+    #[test]
+    fn test_codec_decode() {
+        let mut codec = Base24Codec::new();
+        let mut src = BytesMut::from(&b"x5ggbh"[..]);
+
+        assert_eq!(codec.decode(&mut src).ok(), Some(None));
+        assert!(src.is_empty());
+
+        src.extend_from_slice(b"75YEATXA");
+        assert_eq!(
+            codec.decode(&mut src).ok(),
+            Some(Some(vec![0xFF, 0xFF, 0xFF, 0xFF, 0x88, 0x55, 0x33, 0x11]))
+        );
+        assert_eq!(codec.decode_eof(&mut src).ok(), Some(None));
+
+        src.extend_from_slice(b"ZZ");
+        let err = codec
+            .decode_eof(&mut src)
+            .expect_err("partial group must fail");
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(
+            err.into_inner()
+                .and_then(|err| err.downcast::<Base24Error>().ok())
+                .map(|err| *err),
+            Some(Base24Error::TrailingPartialGroup { chars: 2 })
+        );
+
+        let err = Base24Codec::new()
+            .decode(&mut BytesMut::from(&b"ZZZZZZO"[..]))
+            .expect_err("invalid character must fail");
+        assert_eq!(
+            err.into_inner()
+                .and_then(|err| err.downcast::<Base24Error>().ok())
+                .map(|err| *err),
+            Some(Base24Error::DecodeUnsupportedCharacter {
+                character: 'O',
+                position: 6
+            })
+        );
+    }
+}
@@ -1,11 +1,108 @@
-use thiserror::Error;
+use core::fmt;
 
-#[derive(Error, Debug, PartialEq)]
+/// Errors returned when encoding or decoding fails.
+///
+/// New variants may be added in future releases, so matches on this enum need a
+/// catch-all arm:
+///
+/// ```
+/// use base24::errors::Base24Error;
+///
+/// match base24::decode_u32("ZZZ") {
+///     Ok(_) => {}
+///     Err(Base24Error::DecodeInputLengthInvalid { len }) => assert_eq!(len, 3),
+///     Err(_) => {}
+/// }
+/// ```
+#[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum Base24Error {
-    #[error("Input data length must be a multiple of 4 bytes (32 bits)")]
-    EncodeInputLengthInvalid,
-    #[error("Input data length must be a multiple of 7 chars")]
-    DecodeInputLengthInvalid,
-    #[error("Unsupported character in input: {0:?}")]
-    DecodeUnsupportedCharacter(char),
+    /// `len` is the number of input bytes, which must be a multiple of 4.
+    EncodeInputLengthInvalid {
+        len: usize,
+    },
+    /// `len` is the number of input characters, which must be a multiple of 7.
+    DecodeInputLengthInvalid {
+        len: usize,
+    },
+    /// `position` is the byte offset of `character` in the input.
+    DecodeUnsupportedCharacter {
+        character: char,
+        position: usize,
+    },
+    DecodeValueOverflow(usize),
+    DecodePaddingInvalid,
+    ChecksumMismatch,
+    InvalidAlphabet,
+    InvalidAlias(char),
+    OutputBufferTooSmall,
+    /// The decoded output would be `len` bytes, exceeding the limit of `max` bytes.
+    OutputTooLarge {
+        len: usize,
+        max: usize,
+    },
+    OutputWriteFailed,
+    HexParse,
+    MixedCase,
+    /// A stream of base24 text ended with `chars` characters of an incomplete group.
+    TrailingPartialGroup {
+        chars: usize,
+    },
+    /// Group `n` of a spoken ID has the wrong length or a mismatching check character.
+    SpokenGroupInvalid(usize),
 }
+
+impl fmt::Display for Base24Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Base24Error::EncodeInputLengthInvalid { len } => write!(
+                f,
+                "Input length {} is not a multiple of 4 bytes (32 bits)",
+                len
+            ),
+            Base24Error::DecodeInputLengthInvalid { len } => {
+                write!(f, "Input length {} is not a multiple of 7 chars", len)
+            }
+            Base24Error::DecodeUnsupportedCharacter {
+                character,
+                position,
+            } => write!(
+                f,
+                "Unsupported character {:?} at position {}",
+                character, position
+            ),
+            Base24Error::DecodeValueOverflow(group) => write!(
+                f,
+                "Value of group {} does not fit into 4 bytes (32 bits)",
+                group
+            ),
+            Base24Error::DecodePaddingInvalid => write!(f, "Invalid padding in input"),
+            Base24Error::ChecksumMismatch => write!(f, "Checksum does not match the input"),
+            Base24Error::InvalidAlphabet => {
+                write!(
+                    f,
+                    "Alphabet must consist of exactly 24 unique ASCII characters"
+                )
+            }
+            Base24Error::InvalidAlias(alias) => write!(f, "Invalid alias {:?}", alias),
+            Base24Error::OutputBufferTooSmall => write!(f, "Output buffer is too small"),
+            Base24Error::OutputTooLarge { len, max } => write!(
+                f,
+                "Output of {} bytes exceeds the limit of {} bytes",
+                len, max
+            ),
+            Base24Error::OutputWriteFailed => write!(f, "Writing to the output failed"),
+            Base24Error::HexParse => write!(f, "Input is not a valid hex string"),
+            Base24Error::TrailingPartialGroup { chars } => {
+                write!(f, "Input ended with a partial group of {} chars", chars)
+            }
+            Base24Error::MixedCase => write!(f, "Input mixes uppercase and lowercase letters"),
+            Base24Error::SpokenGroupInvalid(group) => {
+                write!(f, "Group {} has the wrong length or check character", group)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Base24Error {}
@@ -0,0 +1,66 @@
+//! Extension traits for encoding and decoding with method syntax.
+//!
+//! ```
+//! use base24::ext::{FromBase24, ToBase24};
+//!
+//! let encoded = [0x88, 0x55, 0x33, 0x11].to_base24()?;
+//! assert_eq!(encoded, "5YEATXA");
+//! assert_eq!(encoded.from_base24()?, [0x88, 0x55, 0x33, 0x11]);
+//! # Ok::<(), base24::errors::Base24Error>(())
+//! ```
+
+use crate::errors::Base24Error;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Encodes bytes with the default alphabet, like [`encode`](crate::encode).
+pub trait ToBase24 {
+    fn to_base24(&self) -> Result<String, Base24Error>;
+}
+
+/// Decodes base24 text with the default alphabet, like [`decode`](crate::decode).
+pub trait FromBase24 {
+    #[allow(clippy::wrong_self_convention)]
+    fn from_base24(&self) -> Result<Vec<u8>, Base24Error>;
+}
+
+impl ToBase24 for [u8] {
+    fn to_base24(&self) -> Result<String, Base24Error> {
+        crate::encode(self)
+    }
+}
+
+impl FromBase24 for str {
+    fn from_base24(&self) -> Result<Vec<u8>, Base24Error> {
+        crate::decode(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_extension_traits() {
+        let data: &[u8] = &[0xFF, 0xFF, 0xFF, 0xFF];
+        assert_eq!(data.to_base24(), Ok(String::from("X5GGBH7")));
+        let data = [0x88, 0x55, 0x33, 0x11].to_vec();
+        assert_eq!(data.to_base24(), Ok(String::from("5YEATXA")));
+
+        assert_eq!("x5ggbh7".from_base24(), Ok(vec![0xFF, 0xFF, 0xFF, 0xFF]));
+        assert_eq!(
+            String::from("5YEATXA").from_base24(),
+            Ok(vec![0x88, 0x55, 0x33, 0x11])
+        );
+
+        assert_eq!(
+            [1, 2, 3].to_base24(),
+            Err(Base24Error::EncodeInputLengthInvalid { len: 3 })
+        );
+        assert_eq!(
+            "ZZZ".from_base24(),
+            Err(Base24Error::DecodeInputLengthInvalid { len: 3 })
+        );
+    }
+}
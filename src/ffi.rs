@@ -0,0 +1,157 @@
+//! C bindings for encoding and decoding.
+//!
+//! Build a shared library with `cargo rustc --release --features ffi --crate-type cdylib`.
+//! Both functions return the number of bytes written to `out` on success, or one of the
+//! negative error codes below on failure.
+
+use crate::errors::Base24Error;
+use core::slice;
+
+/// A pointer argument was null while its length was not zero.
+pub const BASE24_ERROR_NULL_POINTER: isize = -1;
+/// The input length is not a multiple of 4 bytes (encode) or 7 characters (decode).
+pub const BASE24_ERROR_INPUT_LENGTH: isize = -2;
+/// The input contains a character that is not part of the alphabet.
+pub const BASE24_ERROR_UNSUPPORTED_CHARACTER: isize = -3;
+/// A 7-character group of the input does not fit into 4 bytes.
+pub const BASE24_ERROR_VALUE_OVERFLOW: isize = -4;
+/// The output buffer is too small for the result.
+pub const BASE24_ERROR_OUTPUT_TOO_SMALL: isize = -5;
+/// Any other error.
+pub const BASE24_ERROR_OTHER: isize = -6;
+
+/// Encodes `len` bytes at `data` as ASCII base24 into the `out_len` bytes at `out`.
+///
+/// # Safety
+///
+/// `data` must be valid for reads of `len` bytes and `out` must be valid for writes of
+/// `out_len` bytes. Either pointer may only be null if its length is zero.
+#[no_mangle]
+pub unsafe extern "C" fn base24_encode(
+    data: *const u8,
+    len: usize,
+    out: *mut u8,
+    out_len: usize,
+) -> isize {
+    let (data, out) = match (input(data, len), output(out, out_len)) {
+        (Some(data), Some(out)) => (data, out),
+        _ => return BASE24_ERROR_NULL_POINTER,
+    };
+
+    to_code(crate::with_default(|base24| base24.encode_slice(data, out)))
+}
+
+/// Decodes `len` bytes of ASCII base24 at `data` into the `out_len` bytes at `out`.
+///
+/// # Safety
+///
+/// `data` must be valid for reads of `len` bytes and `out` must be valid for writes of
+/// `out_len` bytes. Either pointer may only be null if its length is zero.
+#[no_mangle]
+pub unsafe extern "C" fn base24_decode(
+    data: *const u8,
+    len: usize,
+    out: *mut u8,
+    out_len: usize,
+) -> isize {
+    let (data, out) = match (input(data, len), output(out, out_len)) {
+        (Some(data), Some(out)) => (data, out),
+        _ => return BASE24_ERROR_NULL_POINTER,
+    };
+
+    to_code(crate::with_default(|base24| base24.decode_ascii(data, out)))
+}
+
+unsafe fn input<'a>(data: *const u8, len: usize) -> Option<&'a [u8]> {
+    match (data.is_null(), len) {
+        (true, 0) => Some(&[]),
+        (true, _) => None,
+        (false, _) => Some(slice::from_raw_parts(data, len)),
+    }
+}
+
+unsafe fn output<'a>(out: *mut u8, out_len: usize) -> Option<&'a mut [u8]> {
+    match (out.is_null(), out_len) {
+        (true, 0) => Some(&mut []),
+        (true, _) => None,
+        (false, _) => Some(slice::from_raw_parts_mut(out, out_len)),
+    }
+}
+
+fn to_code(result: Result<usize, Base24Error>) -> isize {
+    match result {
+        Ok(written) => written as isize,
+        Err(Base24Error::EncodeInputLengthInvalid { .. })
+        | Err(Base24Error::DecodeInputLengthInvalid { .. }) => BASE24_ERROR_INPUT_LENGTH,
+        Err(Base24Error::DecodeUnsupportedCharacter { .. }) => BASE24_ERROR_UNSUPPORTED_CHARACTER,
+        Err(Base24Error::DecodeValueOverflow(_)) => BASE24_ERROR_VALUE_OVERFLOW,
+        Err(Base24Error::OutputBufferTooSmall) => BASE24_ERROR_OUTPUT_TOO_SMALL,
+        Err(_) => BASE24_ERROR_OTHER,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::ptr;
+
+    #[test]
+    fn test_ffi() {
+        let data = [0xFF, 0xFF, 0xFF, 0xFF, 0x88, 0x55, 0x33, 0x11];
+        let mut encoded = [0u8; 14];
+        let mut decoded = [0u8; 8];
+
+        unsafe {
+            assert_eq!(
+                base24_encode(data.as_ptr(), data.len(), encoded.as_mut_ptr(), 14),
+                14
+            );
+            assert_eq!(&encoded, b"X5GGBH75YEATXA");
+
+            assert_eq!(
+                base24_decode(encoded.as_ptr(), 14, decoded.as_mut_ptr(), 8),
+                8
+            );
+            assert_eq!(decoded, data);
+
+            assert_eq!(base24_encode(ptr::null(), 0, ptr::null_mut(), 0), 0);
+        }
+    }
+
+    #[test]
+    fn test_ffi_errors() {
+        let data = [0xFF, 0xFF, 0xFF, 0xFF];
+        let mut out = [0u8; 14];
+
+        unsafe {
+            assert_eq!(
+                base24_encode(ptr::null(), 4, out.as_mut_ptr(), 14),
+                BASE24_ERROR_NULL_POINTER
+            );
+            assert_eq!(
+                base24_encode(data.as_ptr(), 4, ptr::null_mut(), 14),
+                BASE24_ERROR_NULL_POINTER
+            );
+            assert_eq!(
+                base24_encode(data.as_ptr(), 3, out.as_mut_ptr(), 14),
+                BASE24_ERROR_INPUT_LENGTH
+            );
+            assert_eq!(
+                base24_encode(data.as_ptr(), 4, out.as_mut_ptr(), 6),
+                BASE24_ERROR_OUTPUT_TOO_SMALL
+            );
+            assert_eq!(
+                base24_decode(b"ZZZZZZO".as_ptr(), 7, out.as_mut_ptr(), 14),
+                BASE24_ERROR_UNSUPPORTED_CHARACTER
+            );
+            assert_eq!(
+                base24_decode(b"X5GGBH8".as_ptr(), 7, out.as_mut_ptr(), 14),
+                BASE24_ERROR_VALUE_OVERFLOW
+            );
+            assert_eq!(
+                base24_decode(b"\xFFZZZZZZ".as_ptr(), 7, out.as_mut_ptr(), 14),
+                BASE24_ERROR_UNSUPPORTED_CHARACTER
+            );
+        }
+    }
+}
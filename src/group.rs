@@ -0,0 +1,68 @@
+use crate::errors::Base24Error;
+use core::fmt;
+use core::str::FromStr;
+
+/// A single 32-bit value, displayed as and parsed from exactly one 7-char group.
+///
+/// Parsing rejects groups that overflow 32 bits, so every accepted string is canonical
+/// up to case.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Base24Group(pub u32);
+
+impl From<u32> for Base24Group {
+    fn from(value: u32) -> Base24Group {
+        Base24Group(value)
+    }
+}
+
+impl From<Base24Group> for u32 {
+    fn from(group: Base24Group) -> u32 {
+        group.0
+    }
+}
+
+impl FromStr for Base24Group {
+    type Err = Base24Error;
+
+    fn from_str(s: &str) -> Result<Base24Group, Base24Error> {
+        crate::decode_u32(s).map(Base24Group)
+    }
+}
+
+impl fmt::Display for Base24Group {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let digits = crate::with_default(|base24| base24.encode_value(self.0));
+
+        f.pad(core::str::from_utf8(&digits).map_err(|_| fmt::Error)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "alloc")]
+    use alloc::{format, string::ToString};
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_base24_group_display() {
+        assert_eq!(Base24Group::from(0x88553311).to_string(), "5YEATXA");
+        assert_eq!(Base24Group(u32::MAX).to_string(), "X5GGBH7");
+        assert_eq!(format!("{:>9}", Base24Group(1)), "  ZZZZZZA");
+    }
+
+    #[test]
+    fn test_base24_group() {
+        assert_eq!("x5ggbh7".parse(), Ok(Base24Group(u32::MAX)));
+        assert_eq!(u32::from(Base24Group(42)), 42);
+
+        assert_eq!(
+            "X5GGBH8".parse::<Base24Group>(),
+            Err(Base24Error::DecodeValueOverflow(0))
+        );
+        assert_eq!(
+            "ZZZZZZAZ".parse::<Base24Group>(),
+            Err(Base24Error::DecodeInputLengthInvalid { len: 8 })
+        );
+    }
+}
@@ -0,0 +1,244 @@
+use crate::errors::Base24Error;
+use crate::{Base24, BYTES_PER_GROUP, CHARS_PER_GROUP, RADIX};
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Incremental encoder for data that arrives in pieces of arbitrary size.
+///
+/// This is the in-memory counterpart of [`Base24Writer`](crate::stream::Base24Writer):
+/// every complete 4-byte group passed to [`Encoder::update`] is encoded right away, while
+/// a trailing partial group is retained until more data arrives.
+pub struct Encoder {
+    base24: Base24,
+    pending: [u8; BYTES_PER_GROUP],
+    len: usize,
+    encoded: String,
+}
+
+impl Encoder {
+    pub fn new() -> Encoder {
+        Encoder::with_base24(Base24::new())
+    }
+
+    pub fn with_base24(base24: Base24) -> Encoder {
+        Encoder {
+            base24,
+            pending: [0; BYTES_PER_GROUP],
+            len: 0,
+            encoded: String::new(),
+        }
+    }
+
+    /// Encodes all complete groups in `bytes`, together with any bytes retained from
+    /// previous calls.
+    pub fn update(&mut self, bytes: &[u8]) {
+        self.encoded.reserve(
+            (self.len % BYTES_PER_GROUP + bytes.len()) / BYTES_PER_GROUP * CHARS_PER_GROUP,
+        );
+
+        for &byte in bytes {
+            self.pending[self.len % BYTES_PER_GROUP] = byte;
+            self.len += 1;
+
+            if self.len.is_multiple_of(BYTES_PER_GROUP) {
+                let digits = self.base24.encode_group(self.pending);
+                self.encoded
+                    .extend(digits.iter().map(|&digit| char::from(digit)));
+            }
+        }
+    }
+
+    /// Returns the encoding of all bytes passed to [`Encoder::update`].
+    ///
+    /// Fails if the total number of bytes is not a multiple of 4.
+    pub fn finalize(self) -> Result<String, Base24Error> {
+        crate::encoded_len(self.len)
+            .ok_or(Base24Error::EncodeInputLengthInvalid { len: self.len })?;
+
+        Ok(self.encoded)
+    }
+}
+
+impl Default for Encoder {
+    fn default() -> Encoder {
+        Encoder::new()
+    }
+}
+
+/// Incremental decoder for base24 text split at arbitrary points.
+///
+/// Characters are validated as they arrive, and every completed 7-char group is decoded
+/// right away, while a trailing partial group is retained until more text arrives.
+pub struct Decoder {
+    base24: Base24,
+    value: u64,
+    len: usize,
+    position: usize,
+}
+
+impl Decoder {
+    pub fn new() -> Decoder {
+        Decoder::with_base24(Base24::new())
+    }
+
+    pub fn with_base24(base24: Base24) -> Decoder {
+        Decoder {
+            base24,
+            value: 0,
+            len: 0,
+            position: 0,
+        }
+    }
+
+    /// Returns the bytes of all groups completed by `data`.
+    ///
+    /// Positions in errors are byte offsets into the concatenation of all text passed to
+    /// this decoder, and groups are counted from its start. On error the decoder is left
+    /// as it was before the call, so none of `data` is consumed, including groups it
+    /// completed before the invalid character.
+    pub fn update(&mut self, data: &str) -> Result<Vec<u8>, Base24Error> {
+        let mut res = Vec::with_capacity(
+            (self.len % CHARS_PER_GROUP + data.len()) / CHARS_PER_GROUP * BYTES_PER_GROUP,
+        );
+        let (mut value, mut len, mut position) = (self.value, self.len, self.position);
+
+        for character in data.chars() {
+            let digit = self.base24.decode_char(character).ok_or(
+                Base24Error::DecodeUnsupportedCharacter {
+                    character,
+                    position,
+                },
+            )?;
+
+            value = (RADIX as u64) * value + u64::from(digit);
+            position += character.len_utf8();
+            len += 1;
+
+            if len.is_multiple_of(CHARS_PER_GROUP) {
+                let group = len / CHARS_PER_GROUP - 1;
+                let group_value = self.base24.group_value(value, group)?;
+
+                res.extend_from_slice(&self.base24.value_bytes(group_value));
+                value = 0;
+            }
+        }
+
+        self.value = value;
+        self.len = len;
+        self.position = position;
+
+        Ok(res)
+    }
+
+    /// Checks that no partial group is left over.
+    pub fn finalize(self) -> Result<(), Base24Error> {
+        match self.len % CHARS_PER_GROUP {
+            0 => Ok(()),
+            chars => Err(Base24Error::TrailingPartialGroup { chars }),
+        }
+    }
+}
+
+impl Default for Decoder {
+    fn default() -> Decoder {
+        Decoder::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_encoder() {
+        let data: Vec<u8> = (0..=255).collect();
+
+        let mut encoder = Encoder::new();
+        for byte in &data {
+            encoder.update(&[*byte]);
+        }
+        assert_eq!(encoder.finalize(), crate::encode(&data));
+
+        let mut encoder = Encoder::new();
+        for piece in data.chunks(5) {
+            encoder.update(piece);
+        }
+        encoder.update(&[]);
+        assert_eq!(encoder.finalize(), crate::encode(&data));
+
+        assert_eq!(Encoder::new().finalize(), Ok(String::new()));
+    }
+
+    #[test]
+    fn test_encoder_partial_group() {
+        let mut encoder = Encoder::new();
+        encoder.update(&[1, 2, 3]);
+        encoder.update(&[4, 5]);
+
+        assert_eq!(
+            encoder.finalize(),
+            Err(Base24Error::EncodeInputLengthInvalid { len: 5 })
+        );
+    }
+
+    #[test]
+    fn test_decoder() {
+        let data: Vec<u8> = (0..=255).collect();
+        let encoded = crate::encode(&data).expect("error during test encode");
+
+        let mut decoder = Decoder::new();
+        let mut decoded = Vec::new();
+        for piece in encoded.as_bytes().chunks(5) {
+            let piece = core::str::from_utf8(piece).expect("encoded text is ASCII");
+            decoded.extend(decoder.update(piece).expect("error during test decode"));
+        }
+        assert_eq!(decoder.finalize(), Ok(()));
+        assert_eq!(decoded, data);
+
+        let mut decoder = Decoder::new();
+        assert_eq!(decoder.update("X5GG"), Ok(vec![]));
+        assert_eq!(decoder.update("bh7ZZ"), Ok(vec![0xFF, 0xFF, 0xFF, 0xFF]));
+        assert_eq!(decoder.update("ZZZ"), Ok(vec![]));
+        assert_eq!(decoder.update(""), Ok(vec![]));
+        assert_eq!(
+            decoder.finalize(),
+            Err(Base24Error::TrailingPartialGroup { chars: 5 })
+        );
+    }
+
+    #[test]
+    fn test_decoder_errors() {
+        let mut decoder = Decoder::new();
+        assert_eq!(decoder.update("ZZZZZZAZ"), Ok(vec![0, 0, 0, 1]));
+        assert_eq!(
+            decoder.update("Z😘"),
+            Err(Base24Error::DecodeUnsupportedCharacter {
+                character: '😘',
+                position: 9
+            })
+        );
+
+        let mut decoder = Decoder::new();
+        assert_eq!(decoder.update("ZZZZZZAYYY"), Ok(vec![0, 0, 0, 1]));
+        assert_eq!(
+            decoder.update("YYYY"),
+            Err(Base24Error::DecodeValueOverflow(1))
+        );
+
+        // A failing call consumes nothing, not even the groups it completed
+        let mut decoder = Decoder::new();
+        assert_eq!(
+            decoder.update("ZZZZZZAZO"),
+            Err(Base24Error::DecodeUnsupportedCharacter {
+                character: 'O',
+                position: 8
+            })
+        );
+        assert_eq!(decoder.update("ZZZZZZAZ"), Ok(vec![0, 0, 0, 1]));
+        assert_eq!(
+            decoder.finalize(),
+            Err(Base24Error::TrailingPartialGroup { chars: 1 })
+        );
+    }
+}
@@ -1,238 +1,4283 @@
+//! A simple base24 implementation, based on the format described in
+//! <https://www.kuon.ch/post/2020-02-27-base24/>.
+//!
+//! Every 4 bytes of input are encoded as a group of 7 characters from [`ALPHABET`].
+//! [`Base24`] holds the configuration, such as a custom alphabet, and the free functions
+//! use a shared instance with the default alphabet.
+//!
+//! # Naming
+//!
+//! Functions that can fail return a [`Result`](core::result::Result) and have `try_`
+//! aliases, such as [`try_encode`] for [`encode`]. Functions that cannot fail, such as
+//! [`encode_u32`], return their value directly.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "tokio")]
+pub mod async_stream;
+pub mod builder;
+#[cfg(feature = "alloc")]
+mod checksum;
+#[cfg(feature = "codec")]
+pub mod codec;
 pub mod errors;
+#[cfg(feature = "alloc")]
+pub mod ext;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod group;
+#[cfg(feature = "alloc")]
+pub mod incremental;
+#[cfg(feature = "serde")]
+pub mod serde_base24;
+#[cfg(feature = "std")]
+pub mod stream;
+#[cfg(feature = "alloc")]
+pub mod types;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, string::String, vec::Vec};
+#[cfg(feature = "alloc")]
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::fmt;
+use errors::Base24Error;
+
+type Result<T> = core::result::Result<T, Base24Error>;
+
+/// The default alphabet, ordered by digit value.
+///
+/// The characters are not in ASCII order, so sorting base24 strings does not sort them
+/// like the bytes they encode. Use [`Base24::cmp_as_bytes`] to compare by the decoded
+/// bytes instead.
+pub const ALPHABET: &str = "ZAC2B3EF4GH5TK67P8RS9WXY";
+
+/// The number of digits in an alphabet.
+pub const RADIX: usize = 24;
+
+/// The number of bytes encoded by each group.
+pub const BYTES_PER_GROUP: usize = 4;
+
+/// The number of characters in each encoded group.
+pub const CHARS_PER_GROUP: usize = 7;
+
+/// The characters of [`ALPHABET`] in ASCII order.
+///
+/// With this alphabet, sorting encoded strings sorts them like the bytes they encode when
+/// using [`Endianness::Big`]. It is not interoperable with the default alphabet.
+pub const ORDERED_ALPHABET: &str = "23456789ABCEFGHKPRSTWXYZ";
+
+/// The place values of the digits in a 7-char group, most significant first.
+const POWERS: [u32; CHARS_PER_GROUP] = [
+    24 * 24 * 24 * 24 * 24 * 24,
+    24 * 24 * 24 * 24 * 24,
+    24 * 24 * 24 * 24,
+    24 * 24 * 24,
+    24 * 24,
+    24,
+    1,
+];
+
+/// The weights of the digits in a group of [`Base24::encode_spoken`] output when
+/// computing its check character.
+///
+/// All weights are coprime to 24, so changing any single digit changes the check
+/// character.
+#[cfg(feature = "alloc")]
+const SPOKEN_WEIGHTS: [u32; CHARS_PER_GROUP] = [1, 5, 7, 11, 13, 17, 19];
+
+/// Separators skipped by [`Base24::decode_lenient`] in addition to ASCII whitespace.
+pub const DEFAULT_SEPARATORS: &[char] = &['-'];
+
+/// Suggested [repairs](Base24::repair) for the default alphabet, replacing characters
+/// that look like `0` and `1` with the digits of those values, `Z` and `A`.
+pub const DEFAULT_SUBSTITUTIONS: &[(char, char)] = &[
+    ('0', 'Z'),
+    ('O', 'Z'),
+    ('o', 'Z'),
+    ('1', 'A'),
+    ('I', 'A'),
+    ('l', 'A'),
+];
+
+/// Marks bytes in the decode table that are not part of the alphabet.
+const INVALID: u8 = u8::MAX;
+
+// The tables for the default alphabet, generated by `build.rs`
+include!(concat!(env!("OUT_DIR"), "/tables.rs"));
+
+/// The byte order used to interpret each 4-byte chunk as a 32-bit value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Endianness {
+    /// The most significant byte comes first, as in the reference format.
+    #[default]
+    Big,
+    Little,
+}
+
+/// How decoding handles a 7-char group whose value does not fit into 32 bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OnOverflow {
+    /// Fail with [`Base24Error::DecodeValueOverflow`].
+    #[default]
+    Reject,
+    /// Keep the lower 32 bits of the value, as releases without overflow checks did.
+    ///
+    /// This is lossy and non-canonical: several strings decode to the same bytes, and
+    /// re-encoding those bytes does not give back the input. Only use it to read data
+    /// written under the old behavior, and re-encode it to migrate.
+    Wrap,
+}
+
+/// The letter case of encoded output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Case {
+    /// The characters of the alphabet as given, uppercase for the default alphabet.
+    #[default]
+    Upper,
+    Lower,
+}
+
+/// A reusable base24 encoder/decoder.
+///
+/// The lookup tables are built once on construction, so reuse an instance when
+/// encoding or decoding many values.
+///
+/// An instance is never modified after construction, so it is `Send + Sync` and can be
+/// shared between threads, e.g. behind an `Arc`.
+#[derive(Clone)]
+pub struct Base24 {
+    encode_map: [u8; RADIX],
+    decode_map: [u8; 256],
+    endianness: Endianness,
+    on_overflow: OnOverflow,
+}
+
+impl Base24 {
+    pub fn new() -> Base24 {
+        Base24 {
+            encode_map: DEFAULT_ENCODE_MAP,
+            decode_map: DEFAULT_DECODE_MAP,
+            endianness: Endianness::default(),
+            on_overflow: OnOverflow::default(),
+        }
+    }
+
+    /// Creates an instance using the [order-preserving alphabet](ORDERED_ALPHABET).
+    ///
+    /// Encoded strings compare like their bytes as long as they are uppercase and have
+    /// the same length. The output cannot be decoded with the default alphabet.
+    pub fn new_ordered() -> Base24 {
+        Base24::with_alphabet(ORDERED_ALPHABET)
+            .unwrap_or_else(|_| unreachable!("The ordered alphabet is valid. Something is wrong!"))
+    }
+
+    /// Creates an instance using a custom alphabet of 24 unique ASCII characters.
+    ///
+    /// Like the default alphabet, the lowercase form of each character is accepted when
+    /// decoding, unless it is part of the alphabet itself. Alphabets containing multibyte
+    /// characters are rejected, as the lookup tables and the byte offsets reported in
+    /// errors rely on every digit being a single byte.
+    pub fn with_alphabet(alphabet: &str) -> Result<Base24> {
+        if !validate_alphabet(alphabet) {
+            return Err(Base24Error::InvalidAlphabet);
+        }
+
+        let mut encode_map = [0; RADIX];
+        let mut decode_map = [INVALID; 256];
+
+        for (idx, byte) in alphabet.bytes().enumerate() {
+            encode_map[idx] = byte;
+            decode_map[usize::from(byte)] = idx as u8;
+        }
+
+        for (idx, byte) in alphabet.bytes().enumerate() {
+            let lower = usize::from(byte.to_ascii_lowercase());
+
+            if decode_map[lower] == INVALID {
+                decode_map[lower] = idx as u8;
+            }
+        }
+
+        Ok(Base24 {
+            encode_map,
+            decode_map,
+            endianness: Endianness::default(),
+            on_overflow: OnOverflow::default(),
+        })
+    }
+
+    /// Creates an instance using the default alphabet that interprets chunks with the
+    /// given byte order.
+    pub fn with_endianness(endianness: Endianness) -> Base24 {
+        Base24 {
+            endianness,
+            ..Base24::new()
+        }
+    }
+
+    /// Creates an instance using the default alphabet that handles overflowing groups
+    /// according to `on_overflow`.
+    pub fn with_on_overflow(on_overflow: OnOverflow) -> Base24 {
+        Base24 {
+            on_overflow,
+            ..Base24::new()
+        }
+    }
+
+    /// Creates an instance using the default alphabet that additionally decodes each
+    /// `(alias, target)` pair's alias like its target character.
+    ///
+    /// This is meant for user-facing input where confusable characters such as `0` or `O`
+    /// are mistyped. Aliases must be ASCII characters outside of the alphabet, and targets
+    /// characters that are accepted by the alphabet.
+    pub fn with_aliases(aliases: &[(char, char)]) -> Result<Base24> {
+        let mut base24 = Base24::new();
+        base24.add_aliases(aliases)?;
+
+        Ok(base24)
+    }
+
+    /// Adds each `(alias, target)` pair to the decode table, see [`Base24::with_aliases`].
+    fn add_aliases(&mut self, aliases: &[(char, char)]) -> Result<()> {
+        for &(alias, target) in aliases {
+            let byte = u8::try_from(alias)
+                .ok()
+                .filter(|byte| byte.is_ascii() && self.decode_char(alias).is_none());
+            let idx = self.decode_char(target);
+
+            match (byte, idx) {
+                (Some(byte), Some(idx)) => self.decode_map[usize::from(byte)] = idx,
+                _ => return Err(Base24Error::InvalidAlias(alias)),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the `(alias, target)` pairs of the decode table, recovered as the entries
+    /// that are neither alphabet characters nor their other case forms.
+    fn aliases(&self) -> impl Iterator<Item = (char, char)> + '_ {
+        (0..=u8::MAX)
+            .zip(self.decode_map.iter())
+            .filter_map(move |(byte, &idx)| {
+                let target = *self.encode_map.get(usize::from(idx))?;
+
+                if byte.eq_ignore_ascii_case(&target) {
+                    None
+                } else {
+                    Some((char::from(byte), char::from(target)))
+                }
+            })
+    }
+
+    /// Returns the alphabet of this instance, ordered by digit value.
+    #[must_use]
+    pub fn alphabet(&self) -> &str {
+        core::str::from_utf8(&self.encode_map)
+            .unwrap_or_else(|_| unreachable!("The alphabet is ASCII. Something is wrong!"))
+    }
+
+    /// Returns whether `data` is a well-formed base24 string that can be decoded.
+    ///
+    /// Groups that overflow 32 bits are only valid with [`OnOverflow::Wrap`].
+    #[must_use]
+    pub fn is_valid(&self, data: &str) -> bool {
+        let mut len = 0usize;
+        let mut value = 0u64;
+
+        for kar in data.chars() {
+            let digit = match self.decode_char(kar) {
+                Some(digit) => digit,
+                None => return false,
+            };
+
+            value = (RADIX as u64) * value + u64::from(digit);
+            len += 1;
+
+            if len.is_multiple_of(CHARS_PER_GROUP) {
+                if self.group_value(value, len / CHARS_PER_GROUP - 1).is_err() {
+                    return false;
+                }
+
+                value = 0;
+            }
+        }
+
+        decoded_len(len).is_some()
+    }
+
+    /// Encodes `data` as ASCII base24 into the start of `out`, returning the number of
+    /// bytes written.
+    ///
+    /// Use [`encoded_len`] to size `out`.
+    pub fn encode_slice(&self, data: &[u8], out: &mut [u8]) -> Result<usize> {
+        let len = encoded_len(data.len())
+            .ok_or(Base24Error::EncodeInputLengthInvalid { len: data.len() })?;
+
+        if out.len() < len {
+            return Err(Base24Error::OutputBufferTooSmall);
+        }
+
+        for (chunk, digits) in data
+            .chunks(BYTES_PER_GROUP)
+            .zip(out.chunks_mut(CHARS_PER_GROUP))
+        {
+            let value = self.chunk_value(chunk);
+
+            digits.copy_from_slice(&self.encode_value(value));
+        }
+
+        Ok(len)
+    }
+
+    /// Decodes `data` into the start of `out`, returning the number of bytes written.
+    ///
+    /// Use [`decoded_len`] to size `out`.
+    pub fn decode_slice(&self, data: &str, out: &mut [u8]) -> Result<usize> {
+        // All alphabet characters are ASCII, so only non-ASCII input needs the slow path
+        if data.is_ascii() {
+            return self.decode_ascii(data.as_bytes(), out);
+        }
+
+        let len = decoded_len(data.chars().count()).ok_or_else(|| {
+            Base24Error::DecodeInputLengthInvalid {
+                len: data.chars().count(),
+            }
+        })?;
+
+        if out.len() < len {
+            return Err(Base24Error::OutputBufferTooSmall);
+        }
+
+        let mut value = 0u64;
+
+        for (idx, (position, character)) in data.char_indices().enumerate() {
+            let digit =
+                self.decode_char(character)
+                    .ok_or(Base24Error::DecodeUnsupportedCharacter {
+                        character,
+                        position,
+                    })?;
+
+            // 24^7 exceeds u32::MAX, so accumulate in u64 and reject groups that overflow
+            value = (RADIX as u64) * value + u64::from(digit);
+
+            if idx % CHARS_PER_GROUP == CHARS_PER_GROUP - 1 {
+                let group = idx / CHARS_PER_GROUP;
+                let bytes = self.value_bytes(self.group_value(value, group)?);
+
+                let start = group * BYTES_PER_GROUP;
+                out[start..start + BYTES_PER_GROUP].copy_from_slice(&bytes);
+                value = 0;
+            }
+        }
+
+        Ok(len)
+    }
+
+    /// Returns an iterator lazily yielding the base24 characters of `data`.
+    pub fn encode_iter<'a>(&'a self, data: &'a [u8]) -> Result<impl Iterator<Item = char> + 'a> {
+        encoded_len(data.len()).ok_or(Base24Error::EncodeInputLengthInvalid { len: data.len() })?;
+
+        Ok(data.chunks(BYTES_PER_GROUP).flat_map(move |chunk| {
+            let value = self.chunk_value(chunk);
+
+            IntoIterator::into_iter(self.encode_value(value)).map(char::from)
+        }))
+    }
+
+    /// Encodes a single 4-byte chunk to its 7 ASCII characters.
+    #[must_use]
+    #[inline]
+    pub fn encode_group(&self, chunk: [u8; BYTES_PER_GROUP]) -> [u8; CHARS_PER_GROUP] {
+        self.encode_value(self.chunk_value(&chunk))
+    }
+
+    /// Decodes a single group of exactly 7 chars to its 4-byte chunk.
+    pub fn decode_group(&self, group: &str) -> Result<[u8; BYTES_PER_GROUP]> {
+        let len = group.chars().count();
+
+        if len != CHARS_PER_GROUP {
+            return Err(Base24Error::DecodeInputLengthInvalid { len });
+        }
+
+        let mut bytes = [0; BYTES_PER_GROUP];
+        self.decode_slice(group, &mut bytes)?;
+
+        Ok(bytes)
+    }
+
+    /// Decodes `data` to an array of exactly `M` bytes.
+    pub fn decode_array<const M: usize>(&self, data: &str) -> Result<[u8; M]> {
+        let len = data.chars().count();
+
+        if decoded_len(len) != Some(M) {
+            return Err(Base24Error::DecodeInputLengthInvalid { len });
+        }
+
+        let mut bytes = [0; M];
+        self.decode_slice(data, &mut bytes)?;
+
+        Ok(bytes)
+    }
+
+    /// Decodes a UUID from exactly 28 characters.
+    #[cfg(feature = "uuid")]
+    pub fn decode_uuid(&self, data: &str) -> Result<uuid::Uuid> {
+        self.decode_array(data).map(uuid::Uuid::from_bytes)
+    }
+
+    /// Encodes `data` to a string of capacity `N`, failing with
+    /// [`Base24Error::OutputBufferTooSmall`] if the output is longer.
+    #[cfg(feature = "heapless")]
+    pub fn encode_heapless<const N: usize>(&self, data: &[u8]) -> Result<heapless::String<N>> {
+        let len = encoded_len(data.len())
+            .ok_or(Base24Error::EncodeInputLengthInvalid { len: data.len() })?;
+
+        let mut res = heapless::Vec::new();
+        res.resize(len, 0)
+            .map_err(|_| Base24Error::OutputBufferTooSmall)?;
+        self.encode_slice(data, &mut res)?;
+
+        Ok(heapless::String::from_utf8(res)
+            .unwrap_or_else(|_| unreachable!("The alphabet is ASCII. Something is wrong!")))
+    }
+
+    /// Decodes `data` to a vector of capacity `N`, failing with
+    /// [`Base24Error::OutputBufferTooSmall`] if the output is longer.
+    #[cfg(feature = "heapless")]
+    pub fn decode_heapless<const N: usize>(&self, data: &str) -> Result<heapless::Vec<u8, N>> {
+        let len = decoded_len(data.chars().count()).ok_or_else(|| {
+            Base24Error::DecodeInputLengthInvalid {
+                len: data.chars().count(),
+            }
+        })?;
+
+        let mut res = heapless::Vec::new();
+        res.resize(len, 0)
+            .map_err(|_| Base24Error::OutputBufferTooSmall)?;
+        self.decode_slice(data, &mut res)?;
+
+        Ok(res)
+    }
+
+    /// Decodes a single 7-char group to the 32-bit value it represents.
+    pub fn decode_u32(&self, data: &str) -> Result<u32> {
+        Ok(self.chunk_value(&self.decode_group(data)?))
+    }
+
+    /// Decodes two 7-char groups to the 64-bit value they represent.
+    pub fn decode_u64(&self, data: &str) -> Result<u64> {
+        let len = data.chars().count();
+
+        if len != 2 * CHARS_PER_GROUP {
+            return Err(Base24Error::DecodeInputLengthInvalid { len });
+        }
+
+        let mut bytes = [0; 2 * BYTES_PER_GROUP];
+        self.decode_slice(data, &mut bytes)?;
+
+        let high = self.chunk_value(&bytes[..BYTES_PER_GROUP]);
+        let low = self.chunk_value(&bytes[BYTES_PER_GROUP..]);
+
+        Ok(u64::from(high) << 32 | u64::from(low))
+    }
+
+    /// Returns whether `a` and `b` are equal after [normalization](Base24::normalize).
+    #[must_use]
+    pub fn eq_ignore_case(&self, a: &str, b: &str) -> bool {
+        a.chars()
+            .map(|kar| self.normalize_char(kar))
+            .eq(b.chars().map(|kar| self.normalize_char(kar)))
+    }
+
+    /// Encodes `data` directly into a [`fmt::Write`] sink.
+    pub fn encode_fmt<W: core::fmt::Write>(&self, data: &[u8], out: &mut W) -> Result<()> {
+        for kar in self.encode_iter(data)? {
+            out.write_char(kar)
+                .map_err(|_| Base24Error::OutputWriteFailed)?;
+        }
+
+        Ok(())
+    }
+
+    /// Decodes ASCII input by indexing the decode table with its bytes directly.
+    fn decode_ascii(&self, data: &[u8], out: &mut [u8]) -> Result<usize> {
+        let len = decoded_len(data.len())
+            .ok_or(Base24Error::DecodeInputLengthInvalid { len: data.len() })?;
+
+        if out.len() < len {
+            return Err(Base24Error::OutputBufferTooSmall);
+        }
+
+        for (group, (chunk, bytes)) in data
+            .chunks(CHARS_PER_GROUP)
+            .zip(out.chunks_mut(BYTES_PER_GROUP))
+            .enumerate()
+        {
+            let mut value = 0u64;
+
+            for (offset, &byte) in chunk.iter().enumerate() {
+                let digit = self.decode_map[usize::from(byte)];
+
+                if digit == INVALID {
+                    return Err(Base24Error::DecodeUnsupportedCharacter {
+                        character: char::from(byte),
+                        position: group * CHARS_PER_GROUP + offset,
+                    });
+                }
+
+                value = (RADIX as u64) * value + u64::from(digit);
+            }
+
+            let value = self.group_value(value, group)?;
+
+            bytes.copy_from_slice(&self.value_bytes(value));
+        }
+
+        Ok(len)
+    }
+
+    /// Returns the encode table with every character replaced by its lowercase form,
+    /// failing if that form does not decode as the same digit.
+    pub(crate) fn lowercase_map(&self) -> Result<[u8; RADIX]> {
+        let mut map = self.encode_map;
+
+        for (idx, byte) in map.iter_mut().enumerate() {
+            let lower = byte.to_ascii_lowercase();
+
+            if usize::from(self.decode_map[usize::from(lower)]) != idx {
+                return Err(Base24Error::InvalidAlphabet);
+            }
+
+            *byte = lower;
+        }
+
+        Ok(map)
+    }
+
+    /// Converts the accumulated value of group `group` to 32 bits according to the
+    /// overflow policy.
+    #[inline]
+    pub(crate) fn group_value(&self, value: u64, group: usize) -> Result<u32> {
+        match self.on_overflow {
+            OnOverflow::Reject => {
+                u32::try_from(value).map_err(|_| Base24Error::DecodeValueOverflow(group))
+            }
+            OnOverflow::Wrap => Ok(value as u32),
+        }
+    }
+
+    /// Interprets a 4-byte chunk as a 32-bit value.
+    #[inline]
+    fn chunk_value(&self, chunk: &[u8]) -> u32 {
+        let bytes = [chunk[0], chunk[1], chunk[2], chunk[3]];
+
+        match self.endianness {
+            Endianness::Big => u32::from_be_bytes(bytes),
+            Endianness::Little => u32::from_le_bytes(bytes),
+        }
+    }
+
+    /// Converts a decoded 32-bit value back to its 4-byte chunk.
+    #[inline]
+    fn value_bytes(&self, value: u32) -> [u8; BYTES_PER_GROUP] {
+        match self.endianness {
+            Endianness::Big => value.to_be_bytes(),
+            Endianness::Little => value.to_le_bytes(),
+        }
+    }
+
+    /// Encodes a single 32-bit value to its 7 ASCII digits.
+    #[inline]
+    fn encode_value(&self, mut value: u32) -> [u8; CHARS_PER_GROUP] {
+        let mut digits = [0; CHARS_PER_GROUP];
+
+        for (digit, power) in digits.iter_mut().zip(POWERS.iter()) {
+            // One division per digit, the remainder follows from the quotient
+            let idx = value / power;
+            value -= idx * power;
+
+            *digit = self.encode_map[idx as usize];
+        }
+
+        digits
+    }
+
+    /// Maps `kar` to the alphabet character it decodes as, leaving unknown characters
+    /// untouched.
+    #[inline]
+    fn normalize_char(&self, kar: char) -> char {
+        match self.decode_char(kar) {
+            Some(idx) => char::from(self.encode_map[usize::from(idx)]),
+            None => kar,
+        }
+    }
+
+    /// Returns whether `kar` is part of the alphabet, excluding the lowercase forms.
+    #[cfg(feature = "alloc")]
+    fn is_alphabet_char(&self, kar: char) -> bool {
+        self.decode_char(kar).is_some() && self.normalize_char(kar) == kar
+    }
+
+    /// Looks up the digit value of `byte` by comparing it against every entry of the
+    /// decode table, returning the digit and `0xFF` if it was found, `(0, 0)` otherwise.
+    #[cfg(feature = "alloc")]
+    fn decode_byte_ct(&self, byte: u8) -> (u8, u8) {
+        let mut digit = 0;
+        let mut found = 0;
+
+        for (entry, &idx) in (0..=u8::MAX).zip(self.decode_map.iter()) {
+            let mask = ct_eq(byte, entry) & !ct_eq(idx, INVALID);
+
+            digit |= mask & idx;
+            found |= mask;
+        }
+
+        (digit, found)
+    }
+
+    /// Looks up the digit value of `kar`.
+    #[inline]
+    fn decode_char(&self, kar: char) -> Option<u8> {
+        let idx = self.decode_map[usize::from(u8::try_from(kar).ok()?)];
+
+        if idx == INVALID {
+            None
+        } else {
+            Some(idx)
+        }
+    }
+}
+
+/// Computes the check character value of a group of [`Base24::encode_spoken`] output.
+#[cfg(feature = "alloc")]
+fn spoken_check(digits: &[u8]) -> u8 {
+    let sum: u32 = digits
+        .iter()
+        .zip(SPOKEN_WEIGHTS.iter())
+        .map(|(&digit, weight)| u32::from(digit) * weight)
+        .sum();
+
+    (sum % RADIX as u32) as u8
+}
+
+#[cfg(feature = "alloc")]
+impl Base24 {
+    /// Encodes `data`, whose length must be a multiple of 4.
+    ///
+    /// Empty input is valid and encodes to an empty string.
+    #[must_use = "encoding returns the result and leaves the input untouched"]
+    pub fn encode(&self, data: &[u8]) -> Result<String> {
+        let mut res = String::new();
+        self.encode_into(data, &mut res)?;
+
+        Ok(res)
+    }
+
+    /// Alias for [`Base24::encode`].
+    pub fn try_encode(&self, data: &[u8]) -> Result<String> {
+        self.encode(data)
+    }
+
+    /// Alias for [`Base24::decode`].
+    pub fn try_decode(&self, data: &str) -> Result<Vec<u8>> {
+        self.decode(data)
+    }
+
+    /// Encodes a fixed-size array whose length `N` is checked to be a multiple of 4 at
+    /// compile time, so this cannot fail.
+    ///
+    /// ```compile_fail
+    /// let encoded = base24::Base24::new().encode_array(&[1, 2, 3]);
+    /// ```
+    #[must_use]
+    pub fn encode_array<const N: usize>(&self, data: &[u8; N]) -> String {
+        const {
+            assert!(
+                N.is_multiple_of(BYTES_PER_GROUP),
+                "N must be a multiple of 4"
+            )
+        };
+
+        self.encode(data)
+            .unwrap_or_else(|_| unreachable!("The length was checked. Something is wrong!"))
+    }
+
+    /// Encodes the 16 bytes of a UUID to 28 characters.
+    #[cfg(feature = "uuid")]
+    #[must_use]
+    pub fn encode_uuid(&self, uuid: &uuid::Uuid) -> String {
+        self.encode_array(uuid.as_bytes())
+    }
+
+    /// Encodes `data` to a buffer that can be viewed as both `str` and `[u8]`.
+    pub fn encode_owned(&self, data: &[u8]) -> Result<types::Encoded> {
+        Ok(types::Encoded::new(self.encode(data)?))
+    }
+
+    /// Encodes `data` to ASCII bytes instead of a `String`.
+    pub fn encode_to_vec(&self, data: &[u8]) -> Result<Vec<u8>> {
+        let len = encoded_len(data.len())
+            .ok_or(Base24Error::EncodeInputLengthInvalid { len: data.len() })?;
+
+        let mut res = alloc::vec![0; len];
+        self.encode_slice(data, &mut res)?;
+
+        Ok(res)
+    }
+
+    /// Encodes `data` into `out`, replacing its previous contents.
+    pub fn encode_into(&self, data: &[u8], out: &mut String) -> Result<()> {
+        out.clear();
+
+        let len = encoded_len(data.len())
+            .ok_or(Base24Error::EncodeInputLengthInvalid { len: data.len() })?;
+        out.reserve_exact(len);
+
+        for chunk in data.chunks(BYTES_PER_GROUP) {
+            let value = self.chunk_value(chunk);
+
+            out.extend(
+                self.encode_value(value)
+                    .iter()
+                    .map(|&byte| char::from(byte)),
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Encodes the bytes yielded by `iter`, one 4-byte group at a time.
+    ///
+    /// Fails once the iterator is exhausted if it yielded a number of bytes that is not a
+    /// multiple of 4.
+    pub fn encode_from_iter<I: IntoIterator<Item = u8>>(&self, iter: I) -> Result<String> {
+        let iter = iter.into_iter();
+        let mut res = String::with_capacity(iter.size_hint().0 / BYTES_PER_GROUP * CHARS_PER_GROUP);
+        let mut chunk = [0; BYTES_PER_GROUP];
+        let mut len = 0;
+
+        for byte in iter {
+            chunk[len % BYTES_PER_GROUP] = byte;
+            len += 1;
+
+            if len.is_multiple_of(BYTES_PER_GROUP) {
+                res.extend(
+                    self.encode_group(chunk)
+                        .iter()
+                        .map(|&byte| char::from(byte)),
+                );
+            }
+        }
+
+        encoded_len(len).ok_or(Base24Error::EncodeInputLengthInvalid { len })?;
+
+        Ok(res)
+    }
+
+    /// Encodes each of `records` separately and writes them to `out`, separated by `sep`.
+    ///
+    /// All records are checked before anything is written, so a record whose length is
+    /// not a multiple of 4 fails without partial output.
+    #[cfg(feature = "std")]
+    pub fn encode_records<W: std::io::Write>(
+        &self,
+        records: &[&[u8]],
+        sep: &str,
+        out: &mut W,
+    ) -> Result<()> {
+        for record in records {
+            encoded_len(record.len())
+                .ok_or(Base24Error::EncodeInputLengthInvalid { len: record.len() })?;
+        }
+
+        let mut scratch = String::new();
+
+        for (idx, record) in records.iter().enumerate() {
+            if idx > 0 {
+                out.write_all(sep.as_bytes())
+                    .map_err(|_| Base24Error::OutputWriteFailed)?;
+            }
+
+            self.encode_into(record, &mut scratch)?;
+            out.write_all(scratch.as_bytes())
+                .map_err(|_| Base24Error::OutputWriteFailed)?;
+        }
+
+        Ok(())
+    }
+
+    /// Memory-maps the file at `path` and writes its encoding to `out` in chunks, so the
+    /// file is never read into memory as a whole.
+    ///
+    /// A file whose length is not a multiple of 4 fails with
+    /// [`std::io::ErrorKind::InvalidInput`] wrapping a
+    /// [`Base24Error::EncodeInputLengthInvalid`] before anything is written. The file must
+    /// not be modified while it is encoded.
+    #[cfg(feature = "mmap")]
+    pub fn encode_file<W: std::io::Write>(
+        &self,
+        path: &std::path::Path,
+        out: &mut W,
+    ) -> std::io::Result<()> {
+        let file = std::fs::File::open(path)?;
+
+        // SAFETY: The map is only read, and modifying the file meanwhile is documented as
+        // unsupported
+        let map = unsafe { memmap2::Mmap::map(&file)? };
+
+        encoded_len(map.len()).ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                Base24Error::EncodeInputLengthInvalid { len: map.len() },
+            )
+        })?;
+
+        let mut scratch = String::new();
+
+        for chunk in map.chunks(FILE_CHUNK_GROUPS * BYTES_PER_GROUP) {
+            self.encode_into(chunk, &mut scratch)
+                .unwrap_or_else(|_| unreachable!("The length was checked. Something is wrong!"));
+            out.write_all(scratch.as_bytes())?;
+        }
+
+        Ok(())
+    }
+
+    /// Reads base24 text from `reader` until its end and returns the decoded bytes.
+    ///
+    /// The input is decoded group by group while reading, as with
+    /// [`stream::Base24Reader`], so it is never held in memory as a whole. Decoding
+    /// errors are returned as [`std::io::ErrorKind::InvalidData`] wrapping a
+    /// [`Base24Error`].
+    #[cfg(feature = "std")]
+    pub fn decode_reader<R: std::io::Read>(&self, reader: R) -> std::io::Result<Vec<u8>> {
+        use std::io::Read;
+
+        let mut res = Vec::new();
+        stream::Base24Reader::with_base24(self.clone(), reader).read_to_end(&mut res)?;
+
+        Ok(res)
+    }
+
+    /// Encodes a 32-bit value as a single 7-char group.
+    #[must_use]
+    pub fn encode_u32(&self, value: u32) -> String {
+        self.encode_value(value)
+            .iter()
+            .map(|&byte| char::from(byte))
+            .collect()
+    }
+
+    /// Encodes a 64-bit value as two 7-char groups, most significant half first.
+    #[must_use]
+    pub fn encode_u64(&self, value: u64) -> String {
+        let mut res = self.encode_u32((value >> 32) as u32);
+        res.push_str(&self.encode_u32(value as u32));
+
+        res
+    }
+
+    /// Encodes a 64-bit value like [`Base24::encode_u64`], but without leading zero digits.
+    ///
+    /// This is a distinct format that the fixed-length decoders do not accept. Zero is
+    /// encoded as a single zero digit.
+    #[must_use]
+    pub fn encode_varint(&self, value: u64) -> String {
+        let zero = char::from(self.encode_map[0]);
+        let encoded = self.encode_u64(value);
+        let digits = encoded.trim_start_matches(zero);
+
+        if digits.is_empty() {
+            String::from(zero)
+        } else {
+            String::from(digits)
+        }
+    }
+
+    /// Decodes a value produced by [`Base24::encode_varint`].
+    ///
+    /// The input is right-justified to 14 characters with zero digits and decoded like
+    /// [`Base24::decode_u64`]. Errors refer to `data` itself: positions are byte offsets
+    /// into it, and groups are counted from its end, so with more than 7 characters the
+    /// first group is the one that may be shorter.
+    pub fn decode_varint(&self, data: &str) -> Result<u64> {
+        let len = data.chars().count();
+
+        if len == 0 || len > 2 * CHARS_PER_GROUP {
+            return Err(Base24Error::DecodeInputLengthInvalid { len });
+        }
+
+        let padding = 2 * CHARS_PER_GROUP - len;
+        let mut padded: String =
+            core::iter::repeat_n(char::from(self.encode_map[0]), padding).collect();
+        padded.push_str(data);
+
+        // The padding is ASCII, and a group consisting only of it cannot overflow
+        self.decode_u64(&padded).map_err(|err| match err {
+            Base24Error::DecodeUnsupportedCharacter {
+                character,
+                position,
+            } => Base24Error::DecodeUnsupportedCharacter {
+                character,
+                position: position - padding,
+            },
+            Base24Error::DecodeValueOverflow(group) => {
+                Base24Error::DecodeValueOverflow(group - padding / CHARS_PER_GROUP)
+            }
+            err => err,
+        })
+    }
+
+    /// Encodes an arbitrarily large value like [`Base24::encode_varint`], one 7-char group
+    /// per 32 bits, without leading zero digits.
+    ///
+    /// Values that fit into 64 bits thus encode the same as with
+    /// [`Base24::encode_varint`].
+    #[cfg(feature = "bigint")]
+    #[must_use]
+    pub fn encode_bigint(&self, value: &num_bigint::BigUint) -> String {
+        let zero = char::from(self.encode_map[0]);
+        let encoded: String = value
+            .to_u32_digits()
+            .iter()
+            .rev()
+            .flat_map(|&digit| self.encode_value(digit))
+            .map(char::from)
+            .collect();
+        let digits = encoded.trim_start_matches(zero);
+
+        if digits.is_empty() {
+            String::from(zero)
+        } else {
+            String::from(digits)
+        }
+    }
+
+    /// Decodes a value produced by [`Base24::encode_bigint`].
+    ///
+    /// The input is right-justified to a multiple of 7 characters with zero digits. As
+    /// for [`Base24::decode_varint`], errors refer to `data` itself, and the first group
+    /// is the one that may be shorter.
+    #[cfg(feature = "bigint")]
+    pub fn decode_bigint(&self, data: &str) -> Result<num_bigint::BigUint> {
+        let len = data.chars().count();
+
+        if len == 0 {
+            return Err(Base24Error::DecodeInputLengthInvalid { len });
+        }
+
+        let padding = (CHARS_PER_GROUP - len % CHARS_PER_GROUP) % CHARS_PER_GROUP;
+        let zero = char::from(self.encode_map[0]);
+        let padded = core::iter::repeat_n((0, zero), padding);
+
+        let mut digits = Vec::with_capacity((len + padding) / CHARS_PER_GROUP);
+        let mut value = 0u64;
+
+        for (idx, (position, character)) in padded.chain(data.char_indices()).enumerate() {
+            let digit =
+                self.decode_char(character)
+                    .ok_or(Base24Error::DecodeUnsupportedCharacter {
+                        character,
+                        position,
+                    })?;
+
+            value = (RADIX as u64) * value + u64::from(digit);
+
+            if idx % CHARS_PER_GROUP == CHARS_PER_GROUP - 1 {
+                digits.push(self.group_value(value, idx / CHARS_PER_GROUP)?);
+                value = 0;
+            }
+        }
+
+        digits.reverse();
+
+        Ok(num_bigint::BigUint::new(digits))
+    }
+
+    /// Encodes `data` using the lowercase form of the alphabet.
+    ///
+    /// [`Base24::encode`] emits the canonical uppercase form. Fails with
+    /// [`Base24Error::InvalidAlphabet`] if the lowercase form of an alphabet character
+    /// decodes as a different digit, as for [`Case::Lower`] in the builder.
+    pub fn encode_lower(&self, data: &[u8]) -> Result<String> {
+        Base24 {
+            encode_map: self.lowercase_map()?,
+            ..self.clone()
+        }
+        .encode(data)
+    }
+
+    /// Decodes `data`, whose length must be a multiple of 7 chars.
+    ///
+    /// An empty string is valid and decodes to no bytes.
+    #[must_use = "decoding returns the result and leaves the input untouched"]
+    pub fn decode(&self, data: &str) -> Result<Vec<u8>> {
+        let mut res = Vec::new();
+        self.decode_into(data, &mut res)?;
+
+        Ok(res)
+    }
+
+    /// Decodes base24 text given as raw bytes, skipping UTF-8 validation.
+    ///
+    /// Any byte that is not an ASCII alphabet character is reported as
+    /// [`Base24Error::DecodeUnsupportedCharacter`], with non-ASCII bytes converted to the
+    /// `char` of the same value.
+    pub fn decode_bytes(&self, data: &[u8]) -> Result<Vec<u8>> {
+        let len = decoded_len(data.len())
+            .ok_or(Base24Error::DecodeInputLengthInvalid { len: data.len() })?;
+
+        let mut res = alloc::vec![0; len];
+        self.decode_ascii(data, &mut res)?;
+
+        Ok(res)
+    }
+
+    /// Decodes `data` in time that does not depend on the values of its characters.
+    ///
+    /// Instead of indexing the decode table, every character is compared against all of
+    /// its entries, and validity is accumulated so that errors are only reported after the
+    /// entire input was processed. This is slower than [`Base24::decode`] and meant for
+    /// secret material. Once the input is known to be invalid, the error is located with
+    /// the regular, data-dependent decoder.
+    pub fn decode_ct(&self, data: &str) -> Result<Vec<u8>> {
+        let input = data.as_bytes();
+        let len = match decoded_len(input.len()) {
+            Some(len) => len,
+            None => return Err(self.decode_error(data)),
+        };
+
+        let mut res = alloc::vec![0; len];
+        let mut invalid = 0u8;
+
+        for (chunk, bytes) in input
+            .chunks(CHARS_PER_GROUP)
+            .zip(res.chunks_mut(BYTES_PER_GROUP))
+        {
+            let mut value = 0u64;
+
+            for &byte in chunk {
+                let (digit, found) = self.decode_byte_ct(byte);
+
+                invalid |= !found & 1;
+                value = (RADIX as u64) * value + u64::from(digit);
+            }
+
+            // The policy is configuration, so branching on it does not leak the input
+            if self.on_overflow == OnOverflow::Reject {
+                invalid |= ((value >> 32) != 0) as u8;
+            }
+
+            bytes.copy_from_slice(&self.value_bytes(value as u32));
+        }
+
+        if invalid != 0 {
+            return Err(self.decode_error(data));
+        }
+
+        Ok(res)
+    }
+
+    /// Encodes the contents of `data`, like [`Base24::encode`].
+    #[cfg(feature = "bytes")]
+    pub fn encode_bytes(&self, data: &bytes::Bytes) -> Result<String> {
+        self.encode(data)
+    }
+
+    /// Decodes `data` to a [`bytes::BytesMut`] of exactly the decoded length.
+    #[cfg(feature = "bytes")]
+    pub fn decode_to_bytes(&self, data: &str) -> Result<bytes::BytesMut> {
+        let len = decoded_len(data.chars().count()).ok_or_else(|| {
+            Base24Error::DecodeInputLengthInvalid {
+                len: data.chars().count(),
+            }
+        })?;
+
+        let mut res = bytes::BytesMut::zeroed(len);
+        self.decode_slice(data, &mut res)?;
+
+        Ok(res)
+    }
+
+    /// Decodes `data` to a [`smallvec::SmallVec`] that keeps up to 16 bytes, the output
+    /// of 28 characters, inline instead of allocating.
+    ///
+    /// Use [`Base24::decode_small_with`] for a different inline capacity.
+    #[cfg(feature = "smallvec")]
+    pub fn decode_small(&self, data: &str) -> Result<smallvec::SmallVec<[u8; 16]>> {
+        self.decode_small_with(data)
+    }
+
+    /// Decodes `data` to a [`smallvec::SmallVec`] that keeps up to `N` bytes inline.
+    #[cfg(feature = "smallvec")]
+    pub fn decode_small_with<const N: usize>(
+        &self,
+        data: &str,
+    ) -> Result<smallvec::SmallVec<[u8; N]>> {
+        let len = decoded_len(data.chars().count()).ok_or_else(|| {
+            Base24Error::DecodeInputLengthInvalid {
+                len: data.chars().count(),
+            }
+        })?;
+
+        let mut res = smallvec::SmallVec::from_elem(0, len);
+        self.decode_slice(data, &mut res)?;
+
+        Ok(res)
+    }
+
+    /// Encodes `data` to a string that is wiped when dropped.
+    ///
+    /// The output is allocated once at its final size, so no copies of it are left
+    /// behind. Scratch values only live on the stack for the duration of one group.
+    #[cfg(feature = "zeroize")]
+    pub fn encode_zeroizing(&self, data: &[u8]) -> Result<zeroize::Zeroizing<String>> {
+        let mut res = zeroize::Zeroizing::new(String::new());
+        self.encode_into(data, &mut res)?;
+
+        Ok(res)
+    }
+
+    /// Decodes `data` to bytes that are wiped when dropped, like
+    /// [`Base24::encode_zeroizing`].
+    ///
+    /// On failure, the partially decoded output is wiped before the error is returned.
+    #[cfg(feature = "zeroize")]
+    pub fn decode_zeroizing(&self, data: &str) -> Result<zeroize::Zeroizing<Vec<u8>>> {
+        let mut res = zeroize::Zeroizing::new(Vec::new());
+        self.decode_into(data, &mut res)?;
+
+        Ok(res)
+    }
+
+    /// Returns the error [`Base24::decode`] reports for invalid input.
+    fn decode_error(&self, data: &str) -> Base24Error {
+        self.decode(data)
+            .err()
+            .unwrap_or_else(|| unreachable!("The input is invalid. Something is wrong!"))
+    }
+
+    /// Decodes `data` with a one-off `alphabet`, validated like in
+    /// [`Base24::with_alphabet`].
+    ///
+    /// The lookup tables are plain arrays, so this only builds them on the stack.
+    pub fn decode_with_alphabet(data: &str, alphabet: &str) -> Result<Vec<u8>> {
+        Base24::with_alphabet(alphabet)?.decode(data)
+    }
+
+    /// Decodes `data`, failing with [`Base24Error::OutputTooLarge`] before allocating if
+    /// the output would exceed `max_bytes`.
+    ///
+    /// The output size only depends on the input length: every 7 chars decode to 4
+    /// bytes, see [`decoded_len`]. Input of at most `max_bytes / 4 * 7` chars therefore
+    /// always passes the check.
+    pub fn decode_limited(&self, data: &str, max_bytes: usize) -> Result<Vec<u8>> {
+        let len = decoded_len(data.chars().count()).ok_or_else(|| {
+            Base24Error::DecodeInputLengthInvalid {
+                len: data.chars().count(),
+            }
+        })?;
+
+        if len > max_bytes {
+            return Err(Base24Error::OutputTooLarge {
+                len,
+                max: max_bytes,
+            });
+        }
+
+        self.decode(data)
+    }
+
+    /// Decodes `data` into `out`, replacing its previous contents.
+    pub fn decode_into(&self, data: &str, out: &mut Vec<u8>) -> Result<()> {
+        out.clear();
+
+        let len = decoded_len(data.chars().count()).ok_or_else(|| {
+            Base24Error::DecodeInputLengthInvalid {
+                len: data.chars().count(),
+            }
+        })?;
+        out.resize(len, 0);
+
+        if let Err(err) = self.decode_slice(data, out) {
+            out.clear();
+
+            return Err(err);
+        }
+
+        Ok(())
+    }
+
+    /// Decodes the characters yielded by `chars`, consuming them one group at a time.
+    ///
+    /// Positions in errors are byte offsets into the UTF-8 encoding of the yielded
+    /// characters, as if they had been collected into a `String`.
+    pub fn decode_from_chars<I: Iterator<Item = char>>(&self, chars: I) -> Result<Vec<u8>> {
+        let mut res = Vec::with_capacity(
+            decoded_len(chars.size_hint().0 / CHARS_PER_GROUP * CHARS_PER_GROUP).unwrap_or(0),
+        );
+        let mut value = 0u64;
+        let mut len = 0;
+        let mut position = 0;
+
+        for character in chars {
+            let digit =
+                self.decode_char(character)
+                    .ok_or(Base24Error::DecodeUnsupportedCharacter {
+                        character,
+                        position,
+                    })?;
+
+            value = (RADIX as u64) * value + u64::from(digit);
+            position += character.len_utf8();
+            len += 1;
+
+            if len % CHARS_PER_GROUP == 0 {
+                let group = len / CHARS_PER_GROUP - 1;
+                let bytes = self.value_bytes(self.group_value(value, group)?);
+
+                res.extend_from_slice(&bytes);
+                value = 0;
+            }
+        }
+
+        decoded_len(len).ok_or(Base24Error::DecodeInputLengthInvalid { len })?;
+
+        Ok(res)
+    }
+
+    /// Decodes `data` and appends the bytes to `out`, returning how many were appended.
+    ///
+    /// Unlike [`Base24::decode_into`], the previous contents are kept. On failure `out` is
+    /// left as it was.
+    pub fn decode_append(&self, data: &str, out: &mut Vec<u8>) -> Result<usize> {
+        let start = out.len();
+        let len = decoded_len(data.chars().count()).ok_or_else(|| {
+            Base24Error::DecodeInputLengthInvalid {
+                len: data.chars().count(),
+            }
+        })?;
+        out.resize(start + len, 0);
+
+        if let Err(err) = self.decode_slice(data, &mut out[start..]) {
+            out.truncate(start);
+
+            return Err(err);
+        }
+
+        Ok(len)
+    }
+
+    /// Decodes an already tokenized slice of characters.
+    ///
+    /// Positions in errors are byte offsets, as for [`Base24::decode_from_chars`].
+    pub fn decode_chars(&self, chars: &[char]) -> Result<Vec<u8>> {
+        decoded_len(chars.len())
+            .ok_or(Base24Error::DecodeInputLengthInvalid { len: chars.len() })?;
+
+        self.decode_from_chars(chars.iter().copied())
+    }
+
+    /// Encodes `data`, inserting `separator` after every `group_size` characters.
+    ///
+    /// A `group_size` of 0 disables grouping. Use [`Base24::decode_lenient_with`] to decode
+    /// the output.
+    pub fn encode_grouped(
+        &self,
+        data: &[u8],
+        group_size: usize,
+        separator: char,
+    ) -> Result<String> {
+        let encoded = self.encode(data)?;
+
+        if group_size == 0 {
+            return Ok(encoded);
+        }
+
+        let mut res = String::with_capacity(
+            encoded.len() + encoded.len() / group_size * separator.len_utf8(),
+        );
+
+        for (idx, kar) in encoded.chars().enumerate() {
+            if idx > 0 && idx % group_size == 0 {
+                res.push(separator);
+            }
+
+            res.push(kar);
+        }
+
+        Ok(res)
+    }
+
+    /// Encodes `data` into lines of `line_width` characters separated by `\n`.
+    ///
+    /// Lines may split groups. A `line_width` of 0 disables wrapping. Use
+    /// [`Base24::decode_lenient`] to decode the output.
+    pub fn encode_wrapped(&self, data: &[u8], line_width: usize) -> Result<String> {
+        self.encode_grouped(data, line_width, '\n')
+    }
+
+    /// Returns the byte offset and value of every character in `data` that is not part of
+    /// the alphabet.
+    ///
+    /// Unlike [`Base24::decode`], this does not stop at the first invalid character and
+    /// does not check the length of `data`.
+    pub fn validate(&self, data: &str) -> core::result::Result<(), Vec<(usize, char)>> {
+        let invalid: Vec<(usize, char)> = data
+            .char_indices()
+            .filter(|(_, kar)| self.decode_char(*kar).is_none())
+            .collect();
+
+        if invalid.is_empty() {
+            Ok(())
+        } else {
+            Err(invalid)
+        }
+    }
+
+    /// Returns whether `data` is the encoding of some byte sequence, ignoring case.
+    ///
+    /// This requires every group to decode without overflow and the decoded bytes to
+    /// encode back to the same characters, so decoding and re-encoding is the identity up
+    /// to case. Aliases are not canonical, only the alphabet characters and their other
+    /// case forms decoding to the same digit are.
+    #[must_use]
+    pub fn is_canonical(&self, data: &str) -> bool {
+        self.decode(data)
+            .and_then(|bytes| self.encode(&bytes))
+            .is_ok_and(|encoded| {
+                encoded.len() == data.len()
+                    && encoded.chars().zip(data.chars()).all(|(expected, kar)| {
+                        expected.eq_ignore_ascii_case(&kar)
+                            && self.decode_char(expected) == self.decode_char(kar)
+                    })
+            })
+    }
+
+    /// Decodes `data` like [`Base24::decode`], but wraps groups that overflow 32 bits to
+    /// their lower 32 bits instead of failing, returning the indices of those groups.
+    ///
+    /// Overflowing groups are exactly the ones that are not
+    /// [canonical](Base24::is_canonical), so the indices tell which stored values need
+    /// to be re-encoded.
+    pub fn decode_with_report(&self, data: &str) -> Result<(Vec<u8>, Vec<usize>)> {
+        let len = decoded_len(data.chars().count()).ok_or_else(|| {
+            Base24Error::DecodeInputLengthInvalid {
+                len: data.chars().count(),
+            }
+        })?;
+
+        let mut res = Vec::with_capacity(len);
+        let mut overflowed = Vec::new();
+        let mut value = 0u64;
+
+        for (idx, (position, character)) in data.char_indices().enumerate() {
+            let digit =
+                self.decode_char(character)
+                    .ok_or(Base24Error::DecodeUnsupportedCharacter {
+                        character,
+                        position,
+                    })?;
+
+            value = (RADIX as u64) * value + u64::from(digit);
+
+            if idx % CHARS_PER_GROUP == CHARS_PER_GROUP - 1 {
+                if value > u64::from(u32::MAX) {
+                    overflowed.push(idx / CHARS_PER_GROUP);
+                }
+
+                res.extend_from_slice(&self.value_bytes(value as u32));
+                value = 0;
+            }
+        }
+
+        Ok((res, overflowed))
+    }
+
+    /// Returns the unique canonical form of `data` by decoding and re-encoding it.
+    ///
+    /// Normalizing identifiers this way before storing them makes lookups by string
+    /// match. For any [canonical](Base24::is_canonical) input using the default alphabet
+    /// this is equivalent to `to_uppercase`, while groups that overflow are rejected with
+    /// [`Base24Error::DecodeValueOverflow`], or wrapped with [`OnOverflow::Wrap`].
+    pub fn canonicalize(&self, data: &str) -> Result<String> {
+        self.encode(&self.decode(data)?)
+    }
+
+    /// Parses `hex` as hex digit pairs and encodes the bytes they represent.
+    ///
+    /// Odd-length input or characters other than hex digits are rejected with
+    /// [`Base24Error::HexParse`].
+    pub fn encode_hex(&self, hex: &str) -> Result<String> {
+        self.encode(&parse_hex(hex)?)
+    }
+
+    /// Decodes `data` and formats the result as uppercase hex.
+    pub fn decode_to_hex(&self, data: &str) -> Result<String> {
+        Ok(format_hex(&self.decode(data)?))
+    }
+
+    /// Compares `a` and `b` by the bytes they decode to.
+    ///
+    /// The alphabet is not in ASCII order, so this is the order to use for sorted
+    /// indexes over base24 keys rather than comparing the strings themselves.
+    pub fn cmp_as_bytes(&self, a: &str, b: &str) -> Result<Ordering> {
+        Ok(self.decode(a)?.cmp(&self.decode(b)?))
+    }
+
+    /// Decodes `data`, failing with [`Base24Error::MixedCase`] if it contains both
+    /// uppercase and lowercase ASCII letters.
+    ///
+    /// Mixed case in an identifier usually means part of it was lowercased by accident.
+    /// The case check happens before decoding.
+    pub fn decode_reject_mixed_case(&self, data: &str) -> Result<Vec<u8>> {
+        let has_upper = data.bytes().any(|byte| byte.is_ascii_uppercase());
+        let has_lower = data.bytes().any(|byte| byte.is_ascii_lowercase());
+
+        if has_upper && has_lower {
+            return Err(Base24Error::MixedCase);
+        }
+
+        self.decode(data)
+    }
+
+    /// Decodes `data`, accepting only the characters of the alphabet itself.
+    ///
+    /// Unlike [`Base24::decode`], lowercase forms are rejected, so every byte sequence has
+    /// exactly one accepted encoding.
+    pub fn decode_strict(&self, data: &str) -> Result<Vec<u8>> {
+        decoded_len(data.chars().count()).ok_or_else(|| Base24Error::DecodeInputLengthInvalid {
+            len: data.chars().count(),
+        })?;
+
+        if let Some((position, character)) = data
+            .char_indices()
+            .find(|(_, kar)| !self.is_alphabet_char(*kar))
+        {
+            return Err(Base24Error::DecodeUnsupportedCharacter {
+                character,
+                position,
+            });
+        }
+
+        self.decode(data)
+    }
+
+    /// Encodes `data` followed by a checksum group for detecting transcription errors.
+    ///
+    /// The checksum is the CRC-32 (IEEE 802.3, as used by zlib and PNG) of `data`,
+    /// encoded as a single 7-char group holding its 32-bit value.
+    pub fn encode_checked(&self, data: &[u8]) -> Result<String> {
+        let mut res = self.encode(data)?;
+        res.push_str(&self.encode_u32(checksum::crc32(data)));
+
+        Ok(res)
+    }
+
+    /// Decodes data produced by [`Base24::encode_checked`], verifying its checksum.
+    ///
+    /// The input is decoded as a whole, so errors refer to positions and groups of all of
+    /// `data`, including the checksum group.
+    pub fn decode_checked(&self, data: &str) -> Result<Vec<u8>> {
+        let mut res = self.decode(data)?;
+
+        if res.is_empty() {
+            return Err(Base24Error::DecodeInputLengthInvalid { len: 0 });
+        }
+
+        let checksum = self.chunk_value(&res[res.len() - BYTES_PER_GROUP..]);
+        res.truncate(res.len() - BYTES_PER_GROUP);
+
+        if checksum != checksum::crc32(&res) {
+            return Err(Base24Error::ChecksumMismatch);
+        }
+
+        Ok(res)
+    }
+
+    /// Encodes `data` for reading aloud, as dash-separated groups of 7 chars, each
+    /// followed by a check character.
+    ///
+    /// The check character of a group is the digit whose value is the weighted sum of
+    /// the group's digit values modulo 24, using the weights 1, 5, 7, 11, 13, 17 and 19
+    /// from the first to the last digit. Since every weight is coprime to 24, a single
+    /// misheard character within a group is always detected.
+    pub fn encode_spoken(&self, data: &[u8]) -> Result<String> {
+        let encoded = self.encode(data)?;
+        // Each group is followed by its check character and a dash
+        let mut res =
+            String::with_capacity(encoded.len() / CHARS_PER_GROUP * (CHARS_PER_GROUP + 2));
+
+        for chunk in encoded.as_bytes().chunks(CHARS_PER_GROUP) {
+            let mut digits = [0; CHARS_PER_GROUP];
+
+            for (digit, &byte) in digits.iter_mut().zip(chunk) {
+                *digit = self.decode_map[usize::from(byte)];
+            }
+
+            if !res.is_empty() {
+                res.push('-');
+            }
+
+            res.extend(chunk.iter().copied().map(char::from));
+            res.push(char::from(
+                self.encode_map[usize::from(spoken_check(&digits))],
+            ));
+        }
+
+        Ok(res)
+    }
+
+    /// Decodes data produced by [`Base24::encode_spoken`], verifying the check character
+    /// of every group.
+    ///
+    /// A group with the wrong number of characters or a mismatching check character is
+    /// reported as [`Base24Error::SpokenGroupInvalid`] with its index.
+    pub fn decode_spoken(&self, data: &str) -> Result<Vec<u8>> {
+        if data.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut digits = String::with_capacity(data.len());
+        let mut offset = 0;
+
+        for (group, chunk) in data.split('-').enumerate() {
+            let mut values = [0; CHARS_PER_GROUP + 1];
+            let mut count = 0;
+
+            for (idx, kar) in chunk.char_indices() {
+                let digit =
+                    self.decode_char(kar)
+                        .ok_or(Base24Error::DecodeUnsupportedCharacter {
+                            character: kar,
+                            position: offset + idx,
+                        })?;
+
+                if let Some(value) = values.get_mut(count) {
+                    *value = digit;
+                }
+
+                count += 1;
+            }
+
+            let (group_digits, check) = values.split_at(CHARS_PER_GROUP);
+
+            if count != values.len() || spoken_check(group_digits) != check[0] {
+                return Err(Base24Error::SpokenGroupInvalid(group));
+            }
+
+            // All characters were validated as ASCII above
+            digits.push_str(&chunk[..CHARS_PER_GROUP]);
+            offset += chunk.len() + 1;
+        }
+
+        self.decode(&digits)
+    }
+
+    /// Decodes `data` after removing ASCII whitespace and the characters in
+    /// [`DEFAULT_SEPARATORS`].
+    pub fn decode_lenient(&self, data: &str) -> Result<Vec<u8>> {
+        self.decode_lenient_with(data, DEFAULT_SEPARATORS)
+    }
+
+    /// Decodes `data` after removing ASCII whitespace and the given `separators`.
+    pub fn decode_lenient_with(&self, data: &str, separators: &[char]) -> Result<Vec<u8>> {
+        let is_skipped = |kar: &char| kar.is_ascii_whitespace() || separators.contains(kar);
+        let filtered = self.prepare_input_with(data, separators);
+
+        decoded_len(filtered.chars().count()).ok_or_else(|| {
+            Base24Error::DecodeInputLengthInvalid {
+                len: filtered.chars().count(),
+            }
+        })?;
+
+        // Report invalid characters by their position in the unfiltered input
+        if let Some((position, character)) = data
+            .char_indices()
+            .find(|(_, kar)| !is_skipped(kar) && self.decode_char(*kar).is_none())
+        {
+            return Err(Base24Error::DecodeUnsupportedCharacter {
+                character,
+                position,
+            });
+        }
+
+        self.decode(&filtered)
+    }
+
+    /// Removes ASCII whitespace and the characters in [`DEFAULT_SEPARATORS`] from `data`
+    /// and [normalizes](Base24::normalize) the rest.
+    ///
+    /// Input consisting only of alphabet characters is returned as is without
+    /// allocating.
+    #[must_use]
+    pub fn prepare_input<'a>(&self, data: &'a str) -> Cow<'a, str> {
+        self.prepare_input_with(data, DEFAULT_SEPARATORS)
+    }
+
+    /// Like [`Base24::prepare_input`], but removing the given `separators`.
+    ///
+    /// Separators are removed even if they are alphabet characters.
+    fn prepare_input_with<'a>(&self, data: &'a str, separators: &[char]) -> Cow<'a, str> {
+        if data
+            .chars()
+            .all(|kar| self.is_alphabet_char(kar) && !separators.contains(&kar))
+        {
+            return Cow::Borrowed(data);
+        }
+
+        Cow::Owned(
+            data.chars()
+                .filter(|kar| !kar.is_ascii_whitespace() && !separators.contains(kar))
+                .map(|kar| self.normalize_char(kar))
+                .collect(),
+        )
+    }
+
+    /// Decodes whatever remains of `data` after skipping every character that is not part
+    /// of the alphabet, returning the decoded bytes and the byte offset and value of each
+    /// skipped character.
+    ///
+    /// This never fails and is meant for salvaging corrupted input such as OCR output. A
+    /// trailing partial group is padded with zero digits, and groups that overflow keep
+    /// the lower 32 bits of their value.
+    #[must_use]
+    pub fn decode_best_effort(&self, data: &str) -> (Vec<u8>, Vec<(usize, char)>) {
+        let mut digits = Vec::with_capacity(data.len());
+        let mut skipped = Vec::new();
+
+        for (position, kar) in data.char_indices() {
+            match self.decode_char(kar) {
+                Some(digit) => digits.push(digit),
+                None => skipped.push((position, kar)),
+            }
+        }
+
+        let mut res = Vec::with_capacity(digits.len().div_ceil(CHARS_PER_GROUP) * BYTES_PER_GROUP);
+
+        for group in digits.chunks(CHARS_PER_GROUP) {
+            let value = group
+                .iter()
+                .chain(core::iter::repeat(&0))
+                .take(CHARS_PER_GROUP)
+                .fold(0u64, |value, &digit| {
+                    (RADIX as u64) * value + u64::from(digit)
+                });
+
+            res.extend_from_slice(&self.value_bytes(value as u32));
+        }
+
+        (res, skipped)
+    }
+
+    /// Replaces every character in `data` that has an entry in `substitutions` with its
+    /// replacement, returning the repaired string.
+    ///
+    /// Unlike [`Base24::with_aliases`], this produces a corrected string that can be shown
+    /// for confirmation before decoding it.
+    #[must_use]
+    pub fn repair(&self, data: &str, substitutions: &[(char, char)]) -> String {
+        data.chars()
+            .map(|kar| {
+                substitutions
+                    .iter()
+                    .find(|(from, _)| *from == kar)
+                    .map_or(kar, |&(_, to)| to)
+            })
+            .collect()
+    }
+
+    /// Replaces every character with the alphabet character it decodes as, which
+    /// uppercases the lowercase forms accepted by the default alphabet.
+    ///
+    /// Characters that are not part of the alphabet are left untouched.
+    #[must_use]
+    pub fn normalize(&self, data: &str) -> String {
+        data.chars().map(|kar| self.normalize_char(kar)).collect()
+    }
+
+    /// Encodes data of arbitrary length by padding it to a multiple of 4 bytes.
+    ///
+    /// The output is framed so that [`Base24::decode_padded`] restores the exact input:
+    /// the first group encodes the length of the input in bytes as a big-endian 32-bit
+    /// value, followed by the input with zero bytes appended up to the next multiple of 4.
+    /// Input longer than `u32::MAX` bytes is rejected with
+    /// [`Base24Error::EncodeInputLengthInvalid`].
+    pub fn encode_padded(&self, data: &[u8]) -> Result<String> {
+        let len = u32::try_from(data.len())
+            .map_err(|_| Base24Error::EncodeInputLengthInvalid { len: data.len() })?;
+        let padding = (BYTES_PER_GROUP - data.len() % BYTES_PER_GROUP) % BYTES_PER_GROUP;
+
+        let mut padded = Vec::with_capacity(BYTES_PER_GROUP + data.len() + padding);
+        padded.extend_from_slice(&len.to_be_bytes());
+        padded.extend_from_slice(data);
+        padded.resize(padded.len() + padding, 0);
+
+        self.encode(&padded)
+    }
+
+    /// Decodes data produced by [`Base24::encode_padded`].
+    ///
+    /// Fails with [`Base24Error::DecodePaddingInvalid`] unless the recorded length fits
+    /// the number of decoded bytes and the padding consists of zero bytes.
+    pub fn decode_padded(&self, data: &str) -> Result<Vec<u8>> {
+        let mut res = self.decode(data)?;
+
+        if res.len() < BYTES_PER_GROUP {
+            return Err(Base24Error::DecodePaddingInvalid);
+        }
+
+        let len = u32::from_be_bytes([res[0], res[1], res[2], res[3]]) as usize;
+        let padded_len = res.len() - BYTES_PER_GROUP;
+
+        if len > padded_len || padded_len - len >= BYTES_PER_GROUP {
+            return Err(Base24Error::DecodePaddingInvalid);
+        }
+
+        if res[BYTES_PER_GROUP + len..].iter().any(|&byte| byte != 0) {
+            return Err(Base24Error::DecodePaddingInvalid);
+        }
+
+        res.truncate(BYTES_PER_GROUP + len);
+        res.drain(..BYTES_PER_GROUP);
+
+        Ok(res)
+    }
+}
+
+/// Groups encoded at a time by [`Base24::encode_file`].
+#[cfg(feature = "mmap")]
+const FILE_CHUNK_GROUPS: usize = 4096;
+
+/// Groups handled by one rayon task, so that tiny tasks do not dominate.
+#[cfg(feature = "rayon")]
+const PAR_MIN_GROUPS: usize = 1024;
+
+#[cfg(feature = "rayon")]
+impl Base24 {
+    /// Encodes `data` like [`Base24::encode`], splitting the groups across the rayon
+    /// thread pool.
+    ///
+    /// Every group is written to its own place in the output, so the order is preserved.
+    pub fn par_encode(&self, data: &[u8]) -> Result<String> {
+        use rayon::prelude::*;
+
+        let len = encoded_len(data.len())
+            .ok_or(Base24Error::EncodeInputLengthInvalid { len: data.len() })?;
+
+        let mut res = alloc::vec![0; len];
+
+        data.par_chunks(BYTES_PER_GROUP)
+            .zip(res.par_chunks_mut(CHARS_PER_GROUP))
+            .with_min_len(PAR_MIN_GROUPS)
+            .for_each(|(chunk, digits)| {
+                digits.copy_from_slice(&self.encode_value(self.chunk_value(chunk)));
+            });
+
+        Ok(String::from_utf8(res)
+            .unwrap_or_else(|_| unreachable!("The alphabet is ASCII. Something is wrong!")))
+    }
+
+    /// Decodes `data` like [`Base24::decode`], splitting the groups across the rayon
+    /// thread pool.
+    ///
+    /// The input is scanned for unsupported characters before any group is decoded. On
+    /// invalid input, the error is located with [`Base24::decode`], so it is the same.
+    pub fn par_decode(&self, data: &str) -> Result<Vec<u8>> {
+        use rayon::prelude::*;
+
+        let input = data.as_bytes();
+        let len = match decoded_len(input.len()) {
+            Some(len) if data.is_ascii() => len,
+            _ => return self.decode(data),
+        };
+
+        if input
+            .par_iter()
+            .with_min_len(PAR_MIN_GROUPS * CHARS_PER_GROUP)
+            .any(|&byte| self.decode_map[usize::from(byte)] == INVALID)
+        {
+            return Err(self.decode_error(data));
+        }
+
+        let mut res = alloc::vec![0; len];
+
+        let decoded: Result<()> = input
+            .par_chunks(CHARS_PER_GROUP)
+            .zip(res.par_chunks_mut(BYTES_PER_GROUP))
+            .enumerate()
+            .with_min_len(PAR_MIN_GROUPS)
+            .try_for_each(|(group, (chunk, bytes))| {
+                let value = chunk.iter().fold(0u64, |value, &byte| {
+                    let digit = self.decode_map[usize::from(byte)];
+
+                    if digit == INVALID {
+                        unreachable!("The input was scanned. Something is wrong!");
+                    }
+
+                    (RADIX as u64) * value + u64::from(digit)
+                });
+
+                bytes.copy_from_slice(&self.value_bytes(self.group_value(value, group)?));
+
+                Ok(())
+            });
+
+        match decoded {
+            Ok(()) => Ok(res),
+            Err(_) => Err(self.decode_error(data)),
+        }
+    }
+}
+
+impl Default for Base24 {
+    fn default() -> Base24 {
+        Base24::new()
+    }
+}
+
+/// Shows the alphabet and options instead of the lookup tables.
+impl fmt::Debug for Base24 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Base24")
+            .field("alphabet", &self.alphabet())
+            .field("endianness", &self.endianness)
+            .field("on_overflow", &self.on_overflow)
+            .field("aliases", &Aliases(self))
+            .finish()
+    }
+}
+
+/// Formats the aliases of an instance as a map from alias to target.
+struct Aliases<'a>(&'a Base24);
+
+impl fmt::Debug for Aliases<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(self.0.aliases()).finish()
+    }
+}
+
+/// Shows the alphabet, followed by the byte order, overflow policy and aliases if they
+/// are not the default.
+impl fmt::Display for Base24 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "base24({}", self.alphabet())?;
+
+        if self.endianness == Endianness::Little {
+            f.write_str(", little-endian")?;
+        }
+
+        if self.on_overflow == OnOverflow::Wrap {
+            f.write_str(", wrap")?;
+        }
+
+        let mut aliases = self.aliases().peekable();
+
+        if aliases.peek().is_some() {
+            f.write_str(", aliases")?;
+
+            for (alias, target) in aliases {
+                write!(f, " {}:{}", alias, target)?;
+            }
+        }
+
+        f.write_str(")")
+    }
+}
+
+/// Returns the length of the base24 encoding of `input_bytes` bytes, or `None` if that
+/// many bytes cannot be encoded.
+#[must_use]
+pub fn encoded_len(input_bytes: usize) -> Option<usize> {
+    if input_bytes.is_multiple_of(BYTES_PER_GROUP) {
+        Some(input_bytes / BYTES_PER_GROUP * CHARS_PER_GROUP)
+    } else {
+        None
+    }
+}
+
+/// Returns the number of bytes `input_chars` base24 characters decode to, or `None` if
+/// that many characters cannot be decoded.
+#[must_use]
+pub fn decoded_len(input_chars: usize) -> Option<usize> {
+    if input_chars.is_multiple_of(CHARS_PER_GROUP) {
+        Some(input_chars / CHARS_PER_GROUP * BYTES_PER_GROUP)
+    } else {
+        None
+    }
+}
+
+/// Returns whether `alphabet` consists of exactly 24 unique ASCII characters, as required
+/// by [`Base24::with_alphabet`].
+///
+/// This can check a custom alphabet at compile time:
+///
+/// ```
+/// const ALPHABET: &str = "0123456789ABCDEFGHJKMNPQ";
+/// const _: () = assert!(base24::validate_alphabet(ALPHABET));
+/// ```
+#[must_use]
+pub const fn validate_alphabet(alphabet: &str) -> bool {
+    let bytes = alphabet.as_bytes();
+
+    if bytes.len() != RADIX {
+        return false;
+    }
+
+    let mut seen = [false; 128];
+    let mut idx = 0;
+
+    while idx < bytes.len() {
+        let byte = bytes[idx];
+
+        if !byte.is_ascii() || seen[byte as usize] {
+            return false;
+        }
+
+        seen[byte as usize] = true;
+        idx += 1;
+    }
+
+    true
+}
+
+const _: () = assert!(validate_alphabet(ALPHABET));
+const _: () = assert!(validate_alphabet(ORDERED_ALPHABET));
+
+/// Returns `0xFF` if `a == b` and `0` otherwise, without branching.
+#[cfg(feature = "alloc")]
+fn ct_eq(a: u8, b: u8) -> u8 {
+    let diff = u16::from(a ^ b);
+
+    (diff.wrapping_sub(1) >> 8) as u8
+}
+
+/// Parses a string of hex digit pairs, in either case, to the bytes it represents.
+#[cfg(feature = "alloc")]
+fn parse_hex(hex: &str) -> Result<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) {
+        return Err(Base24Error::HexParse);
+    }
+
+    hex.as_bytes()
+        .chunks(2)
+        .map(|pair| {
+            let high = char::from(pair[0]).to_digit(16);
+            let low = char::from(pair[1]).to_digit(16);
+
+            match (high, low) {
+                (Some(high), Some(low)) => Ok((high << 4 | low) as u8),
+                _ => Err(Base24Error::HexParse),
+            }
+        })
+        .collect()
+}
+
+/// Formats `data` as uppercase hex digit pairs.
+#[cfg(feature = "alloc")]
+fn format_hex(data: &[u8]) -> String {
+    data.iter()
+        .flat_map(|&byte| [byte >> 4, byte & 0xF])
+        .map(|digit| {
+            char::from_digit(u32::from(digit), 16)
+                .unwrap_or_else(|| unreachable!("A nibble is a hex digit. Something is wrong!"))
+                .to_ascii_uppercase()
+        })
+        .collect()
+}
+
+/// Runs `f` with the shared instance using the default alphabet.
+///
+/// With `std` the instance is built once and cached, otherwise it is built on every call.
+fn with_default<T>(f: impl FnOnce(&Base24) -> T) -> T {
+    #[cfg(feature = "std")]
+    {
+        static DEFAULT: std::sync::OnceLock<Base24> = std::sync::OnceLock::new();
+
+        f(DEFAULT.get_or_init(Base24::new))
+    }
+
+    #[cfg(not(feature = "std"))]
+    {
+        f(&Base24::new())
+    }
+}
+
+#[must_use]
+pub fn is_valid(data: &str) -> bool {
+    with_default(|base24| base24.is_valid(data))
+}
+
+#[must_use]
+pub fn eq_ignore_case(a: &str, b: &str) -> bool {
+    with_default(|base24| base24.eq_ignore_case(a, b))
+}
+
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn prepare_input(data: &str) -> Cow<'_, str> {
+    with_default(|base24| base24.prepare_input(data))
+}
+
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn decode_best_effort(data: &str) -> (Vec<u8>, Vec<(usize, char)>) {
+    with_default(|base24| base24.decode_best_effort(data))
+}
+
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn repair(data: &str, substitutions: &[(char, char)]) -> String {
+    with_default(|base24| base24.repair(data, substitutions))
+}
+
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn normalize(data: &str) -> String {
+    with_default(|base24| base24.normalize(data))
+}
+
+/// Encodes `data` with the default alphabet.
+///
+/// Accepts anything that can be borrowed as bytes, such as `Vec<u8>` or `Box<[u8]>`.
+/// The returned `String` is a fresh allocation owned by the caller, and the only one
+/// made, as the digits are written to it directly.
+#[cfg(feature = "alloc")]
+#[must_use = "encoding returns the result and leaves the input untouched"]
+pub fn encode(data: impl AsRef<[u8]>) -> Result<String> {
+    with_default(|base24| base24.encode(data.as_ref()))
+}
+
+#[cfg(feature = "alloc")]
+pub fn encode_from_iter<I: IntoIterator<Item = u8>>(iter: I) -> Result<String> {
+    with_default(|base24| base24.encode_from_iter(iter))
+}
+
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn encode_array<const N: usize>(data: &[u8; N]) -> String {
+    with_default(|base24| base24.encode_array(data))
+}
+
+#[cfg(all(feature = "alloc", feature = "uuid"))]
+#[must_use]
+pub fn encode_uuid(uuid: &uuid::Uuid) -> String {
+    with_default(|base24| base24.encode_uuid(uuid))
+}
+
+#[cfg(feature = "alloc")]
+pub fn encode_owned(data: &[u8]) -> Result<types::Encoded> {
+    with_default(|base24| base24.encode_owned(data))
+}
+
+#[cfg(feature = "alloc")]
+pub fn encode_to_vec(data: &[u8]) -> Result<Vec<u8>> {
+    with_default(|base24| base24.encode_to_vec(data))
+}
+
+#[cfg(feature = "alloc")]
+pub fn encode_lower(data: &[u8]) -> Result<String> {
+    with_default(|base24| base24.encode_lower(data))
+}
+
+#[cfg(feature = "std")]
+pub fn encode_records<W: std::io::Write>(records: &[&[u8]], sep: &str, out: &mut W) -> Result<()> {
+    with_default(|base24| base24.encode_records(records, sep, out))
+}
+
+#[cfg(feature = "std")]
+pub fn decode_reader<R: std::io::Read>(reader: R) -> std::io::Result<Vec<u8>> {
+    with_default(|base24| base24.decode_reader(reader))
+}
+
+#[cfg(feature = "mmap")]
+pub fn encode_file<W: std::io::Write>(path: &std::path::Path, out: &mut W) -> std::io::Result<()> {
+    with_default(|base24| base24.encode_file(path, out))
+}
+
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn encode_u32(value: u32) -> String {
+    with_default(|base24| base24.encode_u32(value))
+}
+
+#[must_use]
+pub fn encode_group(chunk: [u8; BYTES_PER_GROUP]) -> [u8; CHARS_PER_GROUP] {
+    with_default(|base24| base24.encode_group(chunk))
+}
+
+pub fn decode_group(group: &str) -> Result<[u8; BYTES_PER_GROUP]> {
+    with_default(|base24| base24.decode_group(group))
+}
+
+pub fn decode_array<const M: usize>(data: &str) -> Result<[u8; M]> {
+    with_default(|base24| base24.decode_array(data))
+}
+
+#[cfg(feature = "uuid")]
+pub fn decode_uuid(data: &str) -> Result<uuid::Uuid> {
+    with_default(|base24| base24.decode_uuid(data))
+}
+
+#[cfg(feature = "heapless")]
+pub fn encode_heapless<const N: usize>(data: &[u8]) -> Result<heapless::String<N>> {
+    with_default(|base24| base24.encode_heapless(data))
+}
+
+#[cfg(feature = "heapless")]
+pub fn decode_heapless<const N: usize>(data: &str) -> Result<heapless::Vec<u8, N>> {
+    with_default(|base24| base24.decode_heapless(data))
+}
+
+#[cfg(feature = "bytes")]
+pub fn encode_bytes(data: &bytes::Bytes) -> Result<String> {
+    with_default(|base24| base24.encode_bytes(data))
+}
+
+#[cfg(feature = "bytes")]
+pub fn decode_to_bytes(data: &str) -> Result<bytes::BytesMut> {
+    with_default(|base24| base24.decode_to_bytes(data))
+}
+
+#[cfg(feature = "smallvec")]
+pub fn decode_small(data: &str) -> Result<smallvec::SmallVec<[u8; 16]>> {
+    with_default(|base24| base24.decode_small(data))
+}
+
+#[cfg(feature = "zeroize")]
+pub fn encode_zeroizing(data: &[u8]) -> Result<zeroize::Zeroizing<String>> {
+    with_default(|base24| base24.encode_zeroizing(data))
+}
+
+#[cfg(feature = "zeroize")]
+pub fn decode_zeroizing(data: &str) -> Result<zeroize::Zeroizing<Vec<u8>>> {
+    with_default(|base24| base24.decode_zeroizing(data))
+}
+
+#[cfg(feature = "rayon")]
+pub fn par_encode(data: &[u8]) -> Result<String> {
+    with_default(|base24| base24.par_encode(data))
+}
+
+#[cfg(feature = "rayon")]
+pub fn par_decode(data: &str) -> Result<Vec<u8>> {
+    with_default(|base24| base24.par_decode(data))
+}
+
+pub fn decode_u32(data: &str) -> Result<u32> {
+    with_default(|base24| base24.decode_u32(data))
+}
+
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn encode_u64(value: u64) -> String {
+    with_default(|base24| base24.encode_u64(value))
+}
+
+pub fn decode_u64(data: &str) -> Result<u64> {
+    with_default(|base24| base24.decode_u64(data))
+}
+
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn encode_varint(value: u64) -> String {
+    with_default(|base24| base24.encode_varint(value))
+}
+
+#[cfg(feature = "alloc")]
+pub fn decode_varint(data: &str) -> Result<u64> {
+    with_default(|base24| base24.decode_varint(data))
+}
+
+#[cfg(feature = "bigint")]
+#[must_use]
+pub fn encode_bigint(value: &num_bigint::BigUint) -> String {
+    with_default(|base24| base24.encode_bigint(value))
+}
+
+#[cfg(feature = "bigint")]
+pub fn decode_bigint(data: &str) -> Result<num_bigint::BigUint> {
+    with_default(|base24| base24.decode_bigint(data))
+}
+
+/// Decodes `data` with the default alphabet.
+///
+/// Accepts anything that can be borrowed as a string slice, such as `String`.
+/// The returned `Vec` is a fresh allocation owned by the caller, and the only one made,
+/// as the bytes are decoded into it directly.
+#[cfg(feature = "alloc")]
+#[must_use = "decoding returns the result and leaves the input untouched"]
+pub fn decode(data: impl AsRef<str>) -> Result<Vec<u8>> {
+    with_default(|base24| base24.decode(data.as_ref()))
+}
+
+/// Alias for [`encode`].
+#[cfg(feature = "alloc")]
+pub fn try_encode(data: impl AsRef<[u8]>) -> Result<String> {
+    encode(data)
+}
+
+/// Alias for [`decode`].
+#[cfg(feature = "alloc")]
+pub fn try_decode(data: impl AsRef<str>) -> Result<Vec<u8>> {
+    decode(data)
+}
+
+#[cfg(feature = "alloc")]
+pub fn decode_limited(data: &str, max_bytes: usize) -> Result<Vec<u8>> {
+    with_default(|base24| base24.decode_limited(data, max_bytes))
+}
+
+#[cfg(feature = "alloc")]
+pub fn validate(data: &str) -> core::result::Result<(), Vec<(usize, char)>> {
+    with_default(|base24| base24.validate(data))
+}
+
+#[cfg(feature = "alloc")]
+pub fn decode_bytes(data: &[u8]) -> Result<Vec<u8>> {
+    with_default(|base24| base24.decode_bytes(data))
+}
+
+#[cfg(feature = "alloc")]
+pub fn decode_ct(data: &str) -> Result<Vec<u8>> {
+    with_default(|base24| base24.decode_ct(data))
+}
+
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn is_canonical(data: &str) -> bool {
+    with_default(|base24| base24.is_canonical(data))
+}
+
+#[cfg(feature = "alloc")]
+pub fn decode_with_report(data: &str) -> Result<(Vec<u8>, Vec<usize>)> {
+    with_default(|base24| base24.decode_with_report(data))
+}
+
+#[cfg(feature = "alloc")]
+pub fn canonicalize(data: &str) -> Result<String> {
+    with_default(|base24| base24.canonicalize(data))
+}
+
+#[cfg(feature = "alloc")]
+pub fn encode_hex(hex: &str) -> Result<String> {
+    with_default(|base24| base24.encode_hex(hex))
+}
+
+#[cfg(feature = "alloc")]
+pub fn decode_to_hex(data: &str) -> Result<String> {
+    with_default(|base24| base24.decode_to_hex(data))
+}
+
+#[cfg(feature = "alloc")]
+pub fn cmp_as_bytes(a: &str, b: &str) -> Result<Ordering> {
+    with_default(|base24| base24.cmp_as_bytes(a, b))
+}
+
+#[cfg(feature = "alloc")]
+pub fn decode_from_chars<I: Iterator<Item = char>>(chars: I) -> Result<Vec<u8>> {
+    with_default(|base24| base24.decode_from_chars(chars))
+}
+
+#[cfg(feature = "alloc")]
+pub fn decode_chars(chars: &[char]) -> Result<Vec<u8>> {
+    with_default(|base24| base24.decode_chars(chars))
+}
+
+#[cfg(feature = "alloc")]
+pub fn decode_reject_mixed_case(data: &str) -> Result<Vec<u8>> {
+    with_default(|base24| base24.decode_reject_mixed_case(data))
+}
+
+#[cfg(feature = "alloc")]
+pub fn decode_strict(data: &str) -> Result<Vec<u8>> {
+    with_default(|base24| base24.decode_strict(data))
+}
+
+#[cfg(feature = "alloc")]
+pub fn encode_wrapped(data: &[u8], line_width: usize) -> Result<String> {
+    with_default(|base24| base24.encode_wrapped(data, line_width))
+}
+
+#[cfg(feature = "alloc")]
+pub fn encode_grouped(data: &[u8], group_size: usize, separator: char) -> Result<String> {
+    with_default(|base24| base24.encode_grouped(data, group_size, separator))
+}
+
+#[cfg(feature = "alloc")]
+pub fn decode_lenient(data: &str) -> Result<Vec<u8>> {
+    with_default(|base24| base24.decode_lenient(data))
+}
+
+#[cfg(feature = "alloc")]
+pub fn encode_checked(data: &[u8]) -> Result<String> {
+    with_default(|base24| base24.encode_checked(data))
+}
+
+#[cfg(feature = "alloc")]
+pub fn decode_checked(data: &str) -> Result<Vec<u8>> {
+    with_default(|base24| base24.decode_checked(data))
+}
+
+#[cfg(feature = "alloc")]
+pub fn encode_spoken(data: &[u8]) -> Result<String> {
+    with_default(|base24| base24.encode_spoken(data))
+}
+
+#[cfg(feature = "alloc")]
+pub fn decode_spoken(data: &str) -> Result<Vec<u8>> {
+    with_default(|base24| base24.decode_spoken(data))
+}
+
+#[cfg(feature = "alloc")]
+pub fn encode_padded(data: &[u8]) -> Result<String> {
+    with_default(|base24| base24.encode_padded(data))
+}
+
+#[cfg(feature = "alloc")]
+pub fn decode_padded(data: &str) -> Result<Vec<u8>> {
+    with_default(|base24| base24.decode_padded(data))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "alloc")]
+    use alloc::{boxed::Box, format, string::ToString, vec};
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_all() {
+        // A few hard coded values
+        let values: Vec<(Vec<u8>, _)> = vec![
+            ("00000000", "ZZZZZZZ"),
+            ("000000000000000000000000", "ZZZZZZZZZZZZZZZZZZZZZ"),
+            ("00000001", "ZZZZZZA"),
+            ("000000010000000100000001", "ZZZZZZAZZZZZZAZZZZZZA"),
+            ("00000010", "ZZZZZZP"),
+            ("00000030", "ZZZZZCZ"),
+            ("88553311", "5YEATXA"),
+            ("FFFFFFFF", "X5GGBH7"),
+            ("FFFFFFFFFFFFFFFFFFFFFFFF", "X5GGBH7X5GGBH7X5GGBH7"),
+            ("FFFFFFFFFFFFFFFFFFFFFFFF", "x5ggbh7x5ggbh7x5ggbh7"),
+            ("1234567887654321", "A64KHWZ5WEPAGG"),
+            ("1234567887654321", "a64khwz5wepagg"),
+            ("FF0001FF001101FF01023399", "XGES63FZZ247C7ZC2ZA6G"),
+            ("FF0001FF001101FF01023399", "xges63fzz247c7zc2za6g"),
+            (
+                "25896984125478546598563251452658",
+                "2FC28KTA66WRST4XAHRRCF237S8Z",
+            ),
+            (
+                "25896984125478546598563251452658",
+                "2fc28kta66wrst4xahrrcf237s8z",
+            ),
+            ("00000001", "ZZZZZZA"),
+            ("00000002", "ZZZZZZC"),
+            ("00000004", "ZZZZZZB"),
+            ("00000008", "ZZZZZZ4"),
+            ("00000010", "ZZZZZZP"),
+            ("00000020", "ZZZZZA4"),
+            ("00000040", "ZZZZZCP"),
+            ("00000080", "ZZZZZ34"),
+            ("00000100", "ZZZZZHP"),
+            ("00000200", "ZZZZZW4"),
+            ("00000400", "ZZZZARP"),
+            ("00000800", "ZZZZ2K4"),
+            ("00001000", "ZZZZFCP"),
+            ("00002000", "ZZZZ634"),
+            ("00004000", "ZZZABHP"),
+            ("00008000", "ZZZC4W4"),
+            ("00010000", "ZZZB8RP"),
+            ("00020000", "ZZZG5K4"),
+            ("00040000", "ZZZRYCP"),
+            ("00080000", "ZZAKX34"),
+            ("00100000", "ZZ229HP"),
+            ("00200000", "ZZEFPW4"),
+            ("00400000", "ZZT7GRP"),
+            ("00800000", "ZAAESK4"),
+            ("01000000", "ZCCK7CP"),
+            ("02000000", "ZB32E34"),
+            ("04000000", "Z4HETHP"),
+            ("08000000", "ZP9KZW4"),
+            ("10000000", "AG8CARP"),
+            ("20000000", "CSHB2K4"),
+            ("40000000", "3694FCP"),
+            ("80000000", "53PP634"),
+        ]
+        .iter()
+        .map(|(str_data, b24_str)| {
+            let data = parse_hex(str_data).expect("error during test hex parse");
+
+            (data, b24_str.to_string())
+        })
+        .collect();
+
+        for (data, b24_str) in values {
+            let decoded = decode(&b24_str).expect("error during test decode");
+            assert_eq!(decoded, data);
+            assert_eq!(
+                encode(&decoded).expect("error during test encode"),
+                b24_str.to_uppercase()
+            );
+            assert_eq!(
+                encode_lower(&decoded).expect("error during test encode"),
+                b24_str.to_lowercase()
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn random_test() {
+        use rand::distributions::Standard;
+        use rand::{thread_rng, Rng};
+
+        let rng = thread_rng();
+
+        for _ in 0..100 {
+            let original_data: Vec<u8> = rng.sample_iter(Standard).take(64).collect();
+
+            let encoded_data = encode(&original_data).expect("error during test encode");
+            let decoded_data = decode(&encoded_data).expect("error during test decode");
+
+            assert_eq!(decoded_data, original_data);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn random_length_test() {
+        use rand::distributions::Standard;
+        use rand::{thread_rng, Rng};
+
+        let mut rng = thread_rng();
+
+        for _ in 0..1000 {
+            let len = rng.gen_range(0, 64) * 4;
+            let original_data: Vec<u8> = (&mut rng).sample_iter(Standard).take(len).collect();
+
+            let encoded_data = encode(&original_data).expect("error during test encode");
+            let decoded_data = decode(&encoded_data).expect("error during test decode");
+
+            assert_eq!(decoded_data, original_data);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn random_input_test() {
+        use rand::distributions::Standard;
+        use rand::seq::SliceRandom;
+        use rand::{thread_rng, Rng};
+
+        let mut rng = thread_rng();
+        let chars: Vec<char> = "ZAC2B3EF4GH5TK67P8RS9WXYzac2b3ef4gh5tk67p8rs9wxyO01I -😘"
+            .chars()
+            .collect();
+
+        for _ in 0..1000 {
+            let len = rng.gen_range(0, 64);
+
+            // Mostly valid characters, to also reach the overflow check
+            let data: String = if rng.gen() {
+                (0..len)
+                    .map(|_| *chars.choose(&mut rng).expect("no chars"))
+                    .collect()
+            } else {
+                (&mut rng)
+                    .sample_iter::<char, _>(Standard)
+                    .take(len)
+                    .collect()
+            };
+
+            match decode(&data) {
+                Ok(decoded) => assert_eq!(
+                    encode(&decoded).expect("error during test encode"),
+                    normalize(&data)
+                ),
+                Err(_) => assert!(!is_canonical(&data)),
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_as_ref_arguments() {
+        let data = vec![0x88, 0x55, 0x33, 0x11];
+        let expected = Ok(String::from("5YEATXA"));
+
+        assert_eq!(encode(&data), expected);
+        assert_eq!(encode(data.clone()), expected);
+        assert_eq!(encode(data.clone().into_boxed_slice()), expected);
+        assert_eq!(encode([0x88, 0x55, 0x33, 0x11]), expected);
+
+        let encoded = String::from("5YEATXA");
+        assert_eq!(decode(&encoded), Ok(data.clone()));
+        assert_eq!(decode(encoded.clone()), Ok(data.clone()));
+        assert_eq!(decode(Box::<str>::from("5YEATXA")), Ok(data));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_try_aliases() {
+        let base24 = Base24::new();
+
+        assert_eq!(
+            try_encode([0x88, 0x55, 0x33, 0x11]),
+            encode([0x88, 0x55, 0x33, 0x11])
+        );
+        assert_eq!(try_decode("5YEATXA"), decode("5YEATXA"));
+        assert_eq!(base24.try_encode(&[1, 2, 3]), base24.encode(&[1, 2, 3]));
+        assert_eq!(base24.try_decode("ZZZZZZO"), base24.decode("ZZZZZZO"));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_reused_instance() {
+        let base24 = Base24::default();
+
+        for value in 0u32..1000 {
+            let data = value.to_be_bytes();
+            let encoded = base24.encode(&data).expect("error during test encode");
+
+            assert_eq!(encoded, encode(data).expect("error during test encode"));
+            assert_eq!(
+                base24.decode(&encoded).expect("error during test decode"),
+                data
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_shared_across_threads() {
+        use std::sync::Arc;
+        use std::thread;
+
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<Base24>();
+
+        let base24 = Arc::new(Base24::with_endianness(Endianness::Little));
+
+        let handles: Vec<_> = (0..8u32)
+            .map(|idx| {
+                let base24 = Arc::clone(&base24);
+
+                thread::spawn(move || {
+                    for value in (idx..10_000).step_by(8) {
+                        let data = value.to_le_bytes();
+                        let encoded = base24.encode(&data).expect("error during test encode");
+
+                        assert_eq!(base24.decode(&encoded), Ok(data.to_vec()));
+                        assert_eq!(base24.decode_u32(&encoded), Ok(value));
+                    }
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().expect("test thread panicked");
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_empty_input() {
+        let base24 = Base24::new();
+
+        assert_eq!(encode([]), Ok(String::new()));
+        assert_eq!(decode(""), Ok(vec![]));
+        assert_eq!(encode_to_vec(&[]), Ok(vec![]));
+        assert_eq!(base24.encode_iter(&[]).map(|iter| iter.count()), Ok(0));
+
+        let mut encoded = String::from("ZZZZZZA");
+        let capacity = encoded.capacity();
+        assert_eq!(base24.encode_into(&[], &mut encoded), Ok(()));
+        assert!(encoded.is_empty());
+        assert_eq!(encoded.capacity(), capacity);
+
+        let mut decoded = vec![1, 2, 3, 4];
+        let capacity = decoded.capacity();
+        assert_eq!(base24.decode_into("", &mut decoded), Ok(()));
+        assert!(decoded.is_empty());
+        assert_eq!(decoded.capacity(), capacity);
+
+        assert_eq!(base24.encode_slice(&[], &mut []), Ok(0));
+        assert_eq!(base24.decode_slice("", &mut []), Ok(0));
+    }
+
+    #[test]
+    fn test_group_constants() {
+        assert_eq!(ALPHABET.len(), RADIX);
+        assert_eq!(encoded_len(BYTES_PER_GROUP), Some(CHARS_PER_GROUP));
+        assert_eq!(decoded_len(CHARS_PER_GROUP), Some(BYTES_PER_GROUP));
+
+        // A group has just enough digits to hold every value of its bytes
+        let max = 1u64 << (8 * BYTES_PER_GROUP);
+        assert!((RADIX as u64).pow(CHARS_PER_GROUP as u32) >= max);
+        assert!((RADIX as u64).pow(CHARS_PER_GROUP as u32 - 1) < max);
+    }
+
+    #[test]
+    fn test_lengths() {
+        assert_eq!(encoded_len(0), Some(0));
+        assert_eq!(encoded_len(4), Some(7));
+        assert_eq!(encoded_len(24), Some(42));
+        assert_eq!(encoded_len(5), None);
+
+        assert_eq!(decoded_len(0), Some(0));
+        assert_eq!(decoded_len(7), Some(4));
+        assert_eq!(decoded_len(42), Some(24));
+        assert_eq!(decoded_len(3), None);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_large_input() {
+        let data: Vec<u8> = (0..16 * 1024).map(|idx| (idx * 7 % 251) as u8).collect();
+
+        let encoded = encode(&data).expect("error during test encode");
+        assert_eq!(encoded.len(), 28 * 1024);
+        assert_eq!(decode(&encoded), Ok(data));
+
+        let mut invalid = encoded.clone();
+        invalid.replace_range(20_000..20_001, "O");
+        assert_eq!(
+            decode(&invalid),
+            Err(Base24Error::DecodeUnsupportedCharacter {
+                character: 'O',
+                position: 20_000
+            })
+        );
+
+        invalid.push('Z');
+        assert_eq!(
+            decode(&invalid),
+            Err(Base24Error::DecodeInputLengthInvalid { len: 28 * 1024 + 1 })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_non_ascii_input() {
+        assert_eq!(
+            decode("ZZZZZZAZZZZZ😘"),
+            Err(Base24Error::DecodeInputLengthInvalid { len: 13 })
+        );
+        assert_eq!(
+            decode("ZZZZZZAZZZZZZÄ"),
+            Err(Base24Error::DecodeUnsupportedCharacter {
+                character: 'Ä',
+                position: 13
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_encode_to_vec() {
+        assert_eq!(
+            encode_to_vec(&[0xFF, 0xFF, 0xFF, 0xFF, 0x88, 0x55, 0x33, 0x11]),
+            Ok(b"X5GGBH75YEATXA".to_vec())
+        );
+        assert_eq!(encode_to_vec(&[]), Ok(Vec::new()));
+        assert_eq!(
+            encode_to_vec(&[1, 2, 3]),
+            Err(Base24Error::EncodeInputLengthInvalid { len: 3 })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_decode_bytes() {
+        assert_eq!(
+            decode_bytes(b"x5ggbh75YEATXA"),
+            Ok(vec![0xFF, 0xFF, 0xFF, 0xFF, 0x88, 0x55, 0x33, 0x11])
+        );
+        assert_eq!(
+            decode_bytes(b"ZZZZZZ\xFF"),
+            Err(Base24Error::DecodeUnsupportedCharacter {
+                character: '\u{FF}',
+                position: 6
+            })
+        );
+        assert_eq!(
+            decode_bytes(b"ZZZ"),
+            Err(Base24Error::DecodeInputLengthInvalid { len: 3 })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "rayon")]
+    fn test_par_encode() {
+        let data: Vec<u8> = (0..4 * 10_000).map(|idx| (idx * 7 % 251) as u8).collect();
+
+        assert_eq!(par_encode(&data), encode(&data));
+        assert_eq!(par_encode(&[]), Ok(String::new()));
+        assert_eq!(
+            par_encode(&data[1..]),
+            Err(Base24Error::EncodeInputLengthInvalid { len: 39_999 })
+        );
+
+        let little = Base24::with_endianness(Endianness::Little);
+        assert_eq!(little.par_encode(&data), little.encode(&data));
+    }
+
+    #[test]
+    #[cfg(feature = "rayon")]
+    fn test_par_decode() {
+        let data: Vec<u8> = (0..4 * 10_000).map(|idx| (idx * 7 % 251) as u8).collect();
+        let encoded = encode(&data).unwrap().to_lowercase();
+
+        assert_eq!(par_decode(&encoded), Ok(data));
+        assert_eq!(par_decode(""), Ok(Vec::new()));
+
+        let mut invalid = encoded.clone();
+        invalid.replace_range(50_000..50_001, "O");
+        assert_eq!(
+            par_decode(&invalid),
+            Err(Base24Error::DecodeUnsupportedCharacter {
+                character: 'O',
+                position: 50_000
+            })
+        );
+
+        let overflow = format!("{}{}{}", encoded, "X5GGBH8", encoded);
+        assert_eq!(
+            par_decode(&overflow),
+            Err(Base24Error::DecodeValueOverflow(10_000))
+        );
+        let wrap = Base24::with_on_overflow(OnOverflow::Wrap);
+        assert_eq!(wrap.par_decode(&overflow), wrap.decode(&overflow));
+
+        assert_eq!(par_decode(&encoded[1..]), decode(&encoded[1..]));
+        assert_eq!(par_decode("ZZZZZZé"), decode("ZZZZZZé"));
+    }
+
+    #[test]
+    #[cfg(feature = "bytes")]
+    fn test_bytes() {
+        let data = bytes::Bytes::from_static(&[0xFF, 0xFF, 0xFF, 0xFF, 0x88, 0x55, 0x33, 0x11]);
+
+        assert_eq!(encode_bytes(&data).as_deref(), Ok("X5GGBH75YEATXA"));
+        assert_eq!(
+            encode_bytes(&data.slice(1..)),
+            Err(Base24Error::EncodeInputLengthInvalid { len: 7 })
+        );
+
+        let decoded = decode_to_bytes("X5GGBH75YEATXA").unwrap();
+        assert_eq!(decoded, data);
+        assert_eq!(decoded.capacity(), 8);
+
+        assert_eq!(
+            decode_to_bytes("X5GGBH75YEATX"),
+            Err(Base24Error::DecodeInputLengthInvalid { len: 13 })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "smallvec")]
+    fn test_decode_small() {
+        let decoded = decode_small("5YEATXA").unwrap();
+        assert_eq!(&decoded[..], [0x88, 0x55, 0x33, 0x11]);
+        assert!(!decoded.spilled());
+
+        let decoded = decode_small(&"X5GGBH7".repeat(5)).unwrap();
+        assert_eq!(&decoded[..], [0xFF; 20]);
+        assert!(decoded.spilled());
+
+        let decoded = Base24::new()
+            .decode_small_with::<32>(&"X5GGBH7".repeat(5))
+            .unwrap();
+        assert!(!decoded.spilled());
+
+        assert_eq!(
+            decode_small("5YEATX"),
+            Err(Base24Error::DecodeInputLengthInvalid { len: 6 })
+        );
+        assert_eq!(
+            decode_small("5YEATXO"),
+            Err(Base24Error::DecodeUnsupportedCharacter {
+                character: 'O',
+                position: 6
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "zeroize")]
+    fn test_zeroizing() {
+        let data = [0xFF, 0xFF, 0xFF, 0xFF, 0x88, 0x55, 0x33, 0x11];
+
+        assert_eq!(encode_zeroizing(&data).unwrap().as_str(), "X5GGBH75YEATXA");
+        assert_eq!(*decode_zeroizing("X5GGBH75YEATXA").unwrap(), data);
+
+        assert_eq!(
+            encode_zeroizing(&data[1..]),
+            Err(Base24Error::EncodeInputLengthInvalid { len: 7 })
+        );
+        assert_eq!(
+            decode_zeroizing("X5GGBH75YEATXO"),
+            Err(Base24Error::DecodeUnsupportedCharacter {
+                character: 'O',
+                position: 13
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_decode_ct() {
+        let data: Vec<u8> = (0..=255).collect();
+        let encoded = encode(&data).expect("error during test encode");
+
+        assert_eq!(decode_ct(&encoded), Ok(data.clone()));
+        assert_eq!(decode_ct(&encoded.to_lowercase()), Ok(data));
+
+        for invalid in &[
+            "ZZZ",
+            "ZZZZZZO",
+            "X5GGBH8",
+            "ABC😘EFG",
+            "ABC😘E",
+            "ZZZZZZAYYYYYYY",
+        ] {
+            assert_eq!(decode_ct(invalid), decode(invalid));
+        }
+
+        let base24 = Base24::with_alphabet("ABCDEFGHIJKLMNOPQRSTUVWa").expect("valid alphabet");
+        assert_eq!(base24.decode_ct("aAAAAAa"), base24.decode("aAAAAAa"));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_into_buffers() {
+        let base24 = Base24::new();
+
+        let mut encoded = String::from("leftover");
+        let mut decoded = vec![0xAB; 16];
+
+        base24
+            .encode_into(&[0xFF, 0xFF, 0xFF, 0xFF, 0, 0, 0, 1], &mut encoded)
+            .expect("error during test encode");
+        assert_eq!(encoded, "X5GGBH7ZZZZZZA");
+
+        base24
+            .decode_into(&encoded, &mut decoded)
+            .expect("error during test decode");
+        assert_eq!(decoded, [0xFF, 0xFF, 0xFF, 0xFF, 0, 0, 0, 1]);
+
+        assert_eq!(
+            base24.encode_into(&[1, 2, 3], &mut encoded),
+            Err(Base24Error::EncodeInputLengthInvalid { len: 3 })
+        );
+        assert!(encoded.is_empty());
+
+        assert_eq!(
+            base24.decode_into("ZZZ", &mut decoded),
+            Err(Base24Error::DecodeInputLengthInvalid { len: 3 })
+        );
+        assert!(decoded.is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_decode_from_chars() {
+        let data = vec![0xFF, 0xFF, 0xFF, 0xFF, 0x88, 0x55, 0x33, 0x11];
+
+        assert_eq!(decode_from_chars("X5GGBH75yeatxa".chars()), Ok(data));
+        assert_eq!(decode_from_chars("".chars()), Ok(vec![]));
+        assert_eq!(
+            decode_from_chars("ZZZZZZA".chars().chain("ZZZZZZC".chars())),
+            decode("ZZZZZZAZZZZZZC")
+        );
+
+        assert_eq!(
+            decode_from_chars("ZZZZZZAZZ".chars()),
+            Err(Base24Error::DecodeInputLengthInvalid { len: 9 })
+        );
+        assert_eq!(
+            decode_from_chars("ZZZZZZA😘Z".chars()),
+            Err(Base24Error::DecodeUnsupportedCharacter {
+                character: '😘',
+                position: 7
+            })
+        );
+        assert_eq!(
+            decode_from_chars("😘ZO".chars()),
+            Err(Base24Error::DecodeUnsupportedCharacter {
+                character: '😘',
+                position: 0
+            })
+        );
+        assert_eq!(
+            decode_from_chars("ZZZZZZAZ😘ZZZZZ".chars()),
+            decode("ZZZZZZAZ😘ZZZZZ")
+        );
+        assert_eq!(
+            decode_from_chars("ZZZZZZAYYYYYYY".chars()),
+            Err(Base24Error::DecodeValueOverflow(1))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_decode_limited() {
+        assert_eq!(decode_limited("X5GGBH7", 4), Ok(vec![0xFF; 4]));
+        assert_eq!(decode_limited("", 0), Ok(vec![]));
+        assert_eq!(
+            decode_limited("X5GGBH7X5GGBH7", 7),
+            Err(Base24Error::OutputTooLarge { len: 8, max: 7 })
+        );
+        assert_eq!(
+            decode_limited("X5GGBH7", 0),
+            Err(Base24Error::OutputTooLarge { len: 4, max: 0 })
+        );
+        assert_eq!(
+            decode_limited("X5GGBH", 0),
+            Err(Base24Error::DecodeInputLengthInvalid { len: 6 })
+        );
+        assert_eq!(
+            decode_limited("X5GGBHO", 4),
+            Err(Base24Error::DecodeUnsupportedCharacter {
+                character: 'O',
+                position: 6
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_decode_append() {
+        let base24 = Base24::new();
+        let mut out = vec![1, 2];
+
+        assert_eq!(base24.decode_append("X5GGBH7", &mut out), Ok(4));
+        assert_eq!(base24.decode_append("", &mut out), Ok(0));
+        assert_eq!(base24.decode_append("5yeatxaZZZZZZA", &mut out), Ok(8));
+        assert_eq!(
+            out,
+            [1, 2, 0xFF, 0xFF, 0xFF, 0xFF, 0x88, 0x55, 0x33, 0x11, 0, 0, 0, 1]
+        );
+
+        assert_eq!(
+            base24.decode_append("ZZZZZZAZZZZZZO", &mut out),
+            Err(Base24Error::DecodeUnsupportedCharacter {
+                character: 'O',
+                position: 13
+            })
+        );
+        assert_eq!(
+            base24.decode_append("ZZZ", &mut out),
+            Err(Base24Error::DecodeInputLengthInvalid { len: 3 })
+        );
+        assert_eq!(out.len(), 14);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_decode_chars() {
+        let chars: Vec<char> = "X5GGBH75yeatxa".chars().collect();
+        assert_eq!(decode_chars(&chars), decode("X5GGBH75yeatxa"));
+        assert_eq!(decode_chars(&[]), Ok(vec![]));
+
+        assert_eq!(
+            decode_chars(&['Z', 'Z', '😘']),
+            Err(Base24Error::DecodeInputLengthInvalid { len: 3 })
+        );
+
+        let chars: Vec<char> = "ZZZZZZAZ😘ZZZZZ".chars().collect();
+        assert_eq!(decode_chars(&chars), decode("ZZZZZZAZ😘ZZZZZ"));
+    }
+
+    #[test]
+    fn test_slices() {
+        let base24 = Base24::new();
+        let data = [0xFF, 0xFF, 0xFF, 0xFF, 0x88, 0x55, 0x33, 0x11];
+
+        let mut encoded = [0u8; 16];
+        let written = base24
+            .encode_slice(&data, &mut encoded)
+            .expect("error during test encode");
+        assert_eq!(written, 14);
+        assert_eq!(&encoded[..written], b"X5GGBH75YEATXA");
+
+        let mut decoded = [0u8; 8];
+        let written = base24
+            .decode_slice("x5ggbh75YEATXA", &mut decoded)
+            .expect("error during test decode");
+        assert_eq!(written, 8);
+        assert_eq!(decoded, data);
+
+        assert_eq!(
+            base24.encode_slice(&data, &mut [0u8; 13]),
+            Err(Base24Error::OutputBufferTooSmall)
+        );
+        assert_eq!(
+            base24.decode_slice("X5GGBH75YEATXA", &mut [0u8; 7]),
+            Err(Base24Error::OutputBufferTooSmall)
+        );
+        assert_eq!(
+            base24.decode_slice("ZZZZZZO", &mut decoded),
+            Err(Base24Error::DecodeUnsupportedCharacter {
+                character: 'O',
+                position: 6
+            })
+        );
+        assert_eq!(
+            base24.decode_slice("ZZZZZZAX5GGBH8", &mut decoded),
+            Err(Base24Error::DecodeValueOverflow(1))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_encode_from_iter() {
+        let data: Vec<u8> = (0..=255).collect();
+
+        assert_eq!(encode_from_iter(data.iter().copied()), encode(&data));
+        assert_eq!(encode_from_iter((0..=255).filter(|_| true)), encode(&data));
+        assert_eq!(
+            encode_from_iter(vec![0x88, 0x55, 0x33, 0x11]),
+            Ok(String::from("5YEATXA"))
+        );
+        assert_eq!(encode_from_iter(None), Ok(String::new()));
+
+        assert_eq!(
+            encode_from_iter(0..5),
+            Err(Base24Error::EncodeInputLengthInvalid { len: 5 })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_encode_records() {
+        let records: [&[u8]; 3] = [&[0xFF; 4], &[], &[0x88, 0x55, 0x33, 0x11, 0, 0, 0, 1]];
+
+        let mut out = Vec::new();
+        assert_eq!(encode_records(&records, ",", &mut out), Ok(()));
+        assert_eq!(out, b"X5GGBH7,,5YEATXAZZZZZZA");
+
+        let mut out = Vec::new();
+        assert_eq!(encode_records(&[], "\n", &mut out), Ok(()));
+        assert!(out.is_empty());
+
+        let mut out = Vec::new();
+        assert_eq!(
+            encode_records(&[&[0xFF; 4], &[1, 2, 3]], "\n", &mut out),
+            Err(Base24Error::EncodeInputLengthInvalid { len: 3 })
+        );
+        assert!(out.is_empty());
+
+        let mut out = [0u8; 10];
+        assert_eq!(
+            encode_records(&records, ",", &mut &mut out[..]),
+            Err(Base24Error::OutputWriteFailed)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_decode_reader() {
+        assert_eq!(
+            decode_reader(&b"X5GGBH75YEATXA"[..]).ok(),
+            Some(vec![0xFF, 0xFF, 0xFF, 0xFF, 0x88, 0x55, 0x33, 0x11])
+        );
+        assert_eq!(decode_reader(&b""[..]).ok(), Some(Vec::new()));
+
+        let err = decode_reader(&b"X5GGBH75YEA"[..]).expect_err("partial group must fail");
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        assert_eq!(
+            err.into_inner()
+                .and_then(|err| err.downcast::<Base24Error>().ok())
+                .map(|err| *err),
+            Some(Base24Error::TrailingPartialGroup { chars: 4 })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "mmap")]
+    fn test_encode_file() {
+        let path = std::env::temp_dir().join(format!("base24-test-{}", std::process::id()));
+        let data: Vec<u8> = (0..4 * 5000).map(|idx| (idx * 7 % 251) as u8).collect();
+
+        let mut out = Vec::new();
+        std::fs::write(&path, &data).expect("error during test write");
+        encode_file(&path, &mut out).expect("error during test encode");
+        assert_eq!(out, encode(&data).unwrap().into_bytes());
+
+        let mut out = Vec::new();
+        std::fs::write(&path, []).expect("error during test write");
+        encode_file(&path, &mut out).expect("error during test encode");
+        assert!(out.is_empty());
+
+        std::fs::write(&path, [1, 2, 3, 4, 5]).expect("error during test write");
+        let err = encode_file(&path, &mut out).expect_err("partial group must fail");
+        assert!(out.is_empty());
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+        assert_eq!(
+            err.into_inner()
+                .and_then(|err| err.downcast::<Base24Error>().ok())
+                .map(|err| *err),
+            Some(Base24Error::EncodeInputLengthInvalid { len: 5 })
+        );
+
+        std::fs::remove_file(&path).expect("error during test cleanup");
+        assert!(encode_file(&path, &mut out).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_encode_iter() {
+        let base24 = Base24::new();
+        let data = [0xFF, 0xFF, 0xFF, 0xFF, 0x88, 0x55, 0x33, 0x11];
+
+        let encoded: String = base24
+            .encode_iter(&data)
+            .expect("error during test encode")
+            .collect();
+        assert_eq!(encoded, "X5GGBH75YEATXA");
+
+        let prefix: String = base24
+            .encode_iter(&data)
+            .expect("error during test encode")
+            .take(9)
+            .collect();
+        assert_eq!(prefix, "X5GGBH75Y");
+
+        assert!(matches!(
+            base24.encode_iter(&[1, 2, 3]),
+            Err(Base24Error::EncodeInputLengthInvalid { len: 3 })
+        ));
+    }
+
+    #[test]
+    fn test_group() {
+        assert_eq!(&encode_group([0x88, 0x55, 0x33, 0x11]), b"5YEATXA");
+        assert_eq!(&encode_group([0xFF; 4]), b"X5GGBH7");
+        assert_eq!(decode_group("5yeatxa"), Ok([0x88, 0x55, 0x33, 0x11]));
+
+        let base24 = Base24::with_endianness(Endianness::Little);
+        assert_eq!(&base24.encode_group([0x11, 0x33, 0x55, 0x88]), b"5YEATXA");
+        assert_eq!(base24.decode_group("5YEATXA"), Ok([0x11, 0x33, 0x55, 0x88]));
+
+        assert_eq!(
+            decode_group("5YEATXA5YEATXA"),
+            Err(Base24Error::DecodeInputLengthInvalid { len: 14 })
+        );
+        assert_eq!(
+            decode_group("5YEATX"),
+            Err(Base24Error::DecodeInputLengthInvalid { len: 6 })
+        );
+        assert_eq!(
+            decode_group("5YEATXO"),
+            Err(Base24Error::DecodeUnsupportedCharacter {
+                character: 'O',
+                position: 6
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_arrays() {
+        assert_eq!(encode_array(&[0x88, 0x55, 0x33, 0x11]), "5YEATXA");
+        assert_eq!(encode_array(&[0xFF; 32]), "X5GGBH7".repeat(8));
+        assert_eq!(encode_array(&[]), "");
+
+        assert_eq!(decode_array("5yeatxa"), Ok([0x88, 0x55, 0x33, 0x11]));
+        assert_eq!(decode_array::<32>(&"X5GGBH7".repeat(8)), Ok([0xFF; 32]));
+        assert_eq!(decode_array::<0>(""), Ok([]));
+
+        assert_eq!(
+            decode_array::<8>("5YEATXA"),
+            Err(Base24Error::DecodeInputLengthInvalid { len: 7 })
+        );
+        assert_eq!(
+            decode_array::<4>("5YEATX"),
+            Err(Base24Error::DecodeInputLengthInvalid { len: 6 })
+        );
+        assert_eq!(
+            decode_array::<4>("5YEATXO"),
+            Err(Base24Error::DecodeUnsupportedCharacter {
+                character: 'O',
+                position: 6
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "heapless")]
+    fn test_heapless() {
+        let data = [0xFF, 0xFF, 0xFF, 0xFF, 0x88, 0x55, 0x33, 0x11];
+
+        assert_eq!(
+            encode_heapless::<14>(&data).as_deref(),
+            Ok("X5GGBH75YEATXA")
+        );
+        assert_eq!(encode_heapless::<16>(&[]).as_deref(), Ok(""));
+        assert_eq!(
+            encode_heapless::<13>(&data),
+            Err(Base24Error::OutputBufferTooSmall)
+        );
+        assert_eq!(
+            encode_heapless::<16>(&data[1..]),
+            Err(Base24Error::EncodeInputLengthInvalid { len: 7 })
+        );
+
+        assert_eq!(
+            decode_heapless::<8>("x5ggbh75yeatxa").as_deref(),
+            Ok(&data[..])
+        );
+        assert_eq!(
+            decode_heapless::<7>("X5GGBH75YEATXA"),
+            Err(Base24Error::OutputBufferTooSmall)
+        );
+        assert_eq!(
+            decode_heapless::<16>("X5GGBH75YEATXO"),
+            Err(Base24Error::DecodeUnsupportedCharacter {
+                character: 'O',
+                position: 13
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "alloc", feature = "uuid"))]
+    fn test_uuid() {
+        let uuid = uuid::Uuid::from_u128(0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0c8);
+        let encoded = encode_uuid(&uuid);
+        assert_eq!(encoded.len(), 28);
+        assert_eq!(decode_uuid(&encoded), Ok(uuid));
+
+        assert_eq!(encode_uuid(&uuid::Uuid::nil()), "Z".repeat(28));
+        assert_eq!(decode_uuid(&"z".repeat(28)), Ok(uuid::Uuid::nil()));
+
+        assert_eq!(
+            decode_uuid(&"Z".repeat(21)),
+            Err(Base24Error::DecodeInputLengthInvalid { len: 21 })
+        );
+        assert_eq!(
+            decode_uuid(&"Z".repeat(35)),
+            Err(Base24Error::DecodeInputLengthInvalid { len: 35 })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_u32() {
+        assert_eq!(encode_u32(0), "ZZZZZZZ");
+        assert_eq!(encode_u32(0x88553311), "5YEATXA");
+        assert_eq!(encode_u32(u32::MAX), "X5GGBH7");
+
+        assert_eq!(decode_u32("ZZZZZZA"), Ok(1));
+        assert_eq!(decode_u32("x5ggbh7"), Ok(u32::MAX));
+        assert_eq!(
+            decode_u32("X5GGBH8"),
+            Err(Base24Error::DecodeValueOverflow(0))
+        );
+        assert_eq!(
+            decode_u32(""),
+            Err(Base24Error::DecodeInputLengthInvalid { len: 0 })
+        );
+        assert_eq!(
+            decode_u32("ZZZZZZAZZZZZZA"),
+            Err(Base24Error::DecodeInputLengthInvalid { len: 14 })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_u64() {
+        assert_eq!(encode_u64(0), "ZZZZZZZZZZZZZZ");
+        assert_eq!(encode_u64(1), "ZZZZZZZZZZZZZA");
+        assert_eq!(encode_u64(0x1234567887654321), "A64KHWZ5WEPAGG");
+        assert_eq!(encode_u64(u64::MAX), "X5GGBH7X5GGBH7");
+
+        assert_eq!(decode_u64("a64khwz5wepagg"), Ok(0x1234567887654321));
+        assert_eq!(
+            decode_u64("ZZZZZZZX5GGBH8"),
+            Err(Base24Error::DecodeValueOverflow(1))
+        );
+        assert_eq!(
+            decode_u64("X5GGBH7"),
+            Err(Base24Error::DecodeInputLengthInvalid { len: 7 })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_validate() {
+        assert_eq!(validate("X5GGBH7"), Ok(()));
+        assert_eq!(validate("X5G"), Ok(()));
+        assert_eq!(
+            validate("O5GG1H7😘I"),
+            Err(vec![(0, 'O'), (4, '1'), (7, '😘'), (11, 'I')])
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_varint() {
+        assert_eq!(encode_varint(0), "Z");
+        assert_eq!(encode_varint(1), "A");
+        assert_eq!(encode_varint(24), "AZ");
+        assert_eq!(encode_varint(0x1234567887654321), "A64KHWZ5WEPAGG");
+        assert_eq!(encode_varint(u64::MAX), "X5GGBH7X5GGBH7");
+
+        for &value in &[0, 1, 23, 24, 575, 0xFFFF_FFFF, 0x1_0000_0000, u64::MAX] {
+            assert_eq!(decode_varint(&encode_varint(value)), Ok(value));
+        }
+
+        assert_eq!(decode_varint("zzza"), Ok(1));
+        assert_eq!(
+            decode_varint(""),
+            Err(Base24Error::DecodeInputLengthInvalid { len: 0 })
+        );
+        assert_eq!(
+            decode_varint("ZZZZZZZZZZZZZZZ"),
+            Err(Base24Error::DecodeInputLengthInvalid { len: 15 })
+        );
+        assert_eq!(
+            decode_varint("YYYYYYY"),
+            Err(Base24Error::DecodeValueOverflow(0))
+        );
+        assert_eq!(
+            decode_varint("YYYYYYYZ"),
+            Err(Base24Error::DecodeValueOverflow(1))
+        );
+        assert_eq!(
+            decode_varint("😘"),
+            Err(Base24Error::DecodeUnsupportedCharacter {
+                character: '😘',
+                position: 0
+            })
+        );
+        assert_eq!(
+            decode_varint("ZZO"),
+            Err(Base24Error::DecodeUnsupportedCharacter {
+                character: 'O',
+                position: 2
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "bigint")]
+    fn test_bigint() {
+        use num_bigint::BigUint;
+
+        assert_eq!(encode_bigint(&BigUint::from(0u32)), "Z");
+        assert_eq!(encode_bigint(&BigUint::from(24u32)), "AZ");
+        assert_eq!(
+            encode_bigint(&BigUint::from(0x1234567887654321u64)),
+            encode_varint(0x1234567887654321)
+        );
+
+        let value = BigUint::from(u128::MAX);
+        assert_eq!(encode_bigint(&value), "X5GGBH7".repeat(4));
+        assert_eq!(decode_bigint(&"x5ggbh7".repeat(4)), Ok(value));
+
+        for &value in &[0, 1, 23, 24, 575, 0xFFFF_FFFF, 0x1_0000_0000, u64::MAX] {
+            let value = BigUint::from(value);
+            assert_eq!(decode_bigint(&encode_bigint(&value)), Ok(value));
+        }
+
+        assert_eq!(decode_bigint("ZZZA"), Ok(BigUint::from(1u32)));
+        assert_eq!(
+            decode_bigint(""),
+            Err(Base24Error::DecodeInputLengthInvalid { len: 0 })
+        );
+        assert_eq!(
+            decode_bigint("YYYYYYYZ"),
+            Err(Base24Error::DecodeValueOverflow(1))
+        );
+        assert_eq!(
+            decode_bigint("ZZO"),
+            Err(Base24Error::DecodeUnsupportedCharacter {
+                character: 'O',
+                position: 2
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_is_canonical() {
+        assert!(is_canonical(""));
+        assert!(is_canonical("X5GGBH7"));
+        assert!(is_canonical("x5ggbh7ZZZZZZA"));
+        assert!(!is_canonical("X5GGBH8"));
+        assert!(!is_canonical("YYYYYYY"));
+        assert!(!is_canonical("ZZZZZZO"));
+        assert!(!is_canonical("ZZZ"));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_decode_with_report() {
+        assert_eq!(
+            decode_with_report("X5GGBH7zzzzzza"),
+            Ok((vec![0xFF, 0xFF, 0xFF, 0xFF, 0, 0, 0, 1], vec![]))
+        );
+        assert_eq!(decode_with_report(""), Ok((vec![], vec![])));
+        assert_eq!(
+            decode_with_report("ZZZZZZAYYYYYYYX5GGBH8"),
+            Ok((
+                vec![0, 0, 0, 1, 0x11, 0x5F, 0xFF, 0xFF, 0, 0, 0, 1],
+                vec![1, 2]
+            ))
+        );
+
+        assert_eq!(
+            decode_with_report("YYYYYYYZZZZZZO"),
+            Err(Base24Error::DecodeUnsupportedCharacter {
+                character: 'O',
+                position: 13
+            })
+        );
+        assert_eq!(
+            decode_with_report("ZZZ"),
+            Err(Base24Error::DecodeInputLengthInvalid { len: 3 })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_canonicalize() {
+        assert_eq!(
+            canonicalize("x5GgbH7zzzzzza"),
+            Ok("X5GGBH7ZZZZZZA".to_string())
+        );
+        assert_eq!(
+            canonicalize("X5GGBH7").and_then(|canonical| canonicalize(&canonical)),
+            Ok("X5GGBH7".to_string())
+        );
+        assert_eq!(
+            canonicalize("ZZZZZZAX5GGBH8"),
+            Err(Base24Error::DecodeValueOverflow(1))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_hex() {
+        assert_eq!(encode_hex("88553311"), Ok(String::from("5YEATXA")));
+        assert_eq!(encode_hex("ff0001FF"), encode([0xFF, 0x00, 0x01, 0xFF]));
+        assert_eq!(encode_hex(""), Ok(String::new()));
+        assert_eq!(decode_to_hex("5yeatxa"), Ok(String::from("88553311")));
+        assert_eq!(
+            decode_to_hex("XGES63FZZ247C7ZC2ZA6G"),
+            Ok(String::from("FF0001FF001101FF01023399"))
+        );
+
+        assert_eq!(encode_hex("8855331"), Err(Base24Error::HexParse));
+        assert_eq!(encode_hex("8855331G"), Err(Base24Error::HexParse));
+        assert_eq!(encode_hex("+1553311"), Err(Base24Error::HexParse));
+        assert_eq!(encode_hex("885533é"), Err(Base24Error::HexParse));
+        assert_eq!(
+            encode_hex("885533"),
+            Err(Base24Error::EncodeInputLengthInvalid { len: 3 })
+        );
+        assert_eq!(
+            decode_to_hex("5YEATX"),
+            Err(Base24Error::DecodeInputLengthInvalid { len: 6 })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_cmp_as_bytes() {
+        // "ZZZZZZ2" sorts before "ZZZZZZC" as a string, but encodes the larger value
+        assert_eq!(encode([0, 0, 0, 2]), Ok(String::from("ZZZZZZC")));
+        assert_eq!(encode([0, 0, 0, 3]), Ok(String::from("ZZZZZZ2")));
+        assert!("ZZZZZZ2" < "ZZZZZZC");
+        assert_eq!(cmp_as_bytes("ZZZZZZ2", "ZZZZZZC"), Ok(Ordering::Greater));
+
+        assert_eq!(cmp_as_bytes("zzzzzz2", "ZZZZZZ2"), Ok(Ordering::Equal));
+        assert_eq!(cmp_as_bytes("", "ZZZZZZZ"), Ok(Ordering::Less));
+        assert_eq!(
+            cmp_as_bytes("ZZZZZZA", "ZZZZZZZZZZZZZZ"),
+            Ok(Ordering::Greater)
+        );
+
+        let mut keys = vec!["ZZZZZZ2", "ZZZZZZA", "ZZZZZZC", "ZZZZZZZ"];
+        keys.sort_by(|a, b| cmp_as_bytes(a, b).expect("error during test decode"));
+        assert_eq!(keys, ["ZZZZZZZ", "ZZZZZZA", "ZZZZZZC", "ZZZZZZ2"]);
+
+        assert_eq!(
+            cmp_as_bytes("ZZZZZZA", "ZZZZZZ"),
+            Err(Base24Error::DecodeInputLengthInvalid { len: 6 })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_reject_mixed_case() {
+        assert_eq!(decode_reject_mixed_case("X5GGBH7"), Ok(vec![0xFF; 4]));
+        assert_eq!(decode_reject_mixed_case("x5ggbh7"), Ok(vec![0xFF; 4]));
+        assert_eq!(decode_reject_mixed_case("2345678"), decode("2345678"));
+        assert_eq!(
+            decode_reject_mixed_case("X5ggBH7"),
+            Err(Base24Error::MixedCase)
+        );
+        assert_eq!(
+            decode_reject_mixed_case("X5GGBHz"),
+            Err(Base24Error::MixedCase)
+        );
+        assert_eq!(decode("X5ggBH7"), Ok(vec![0xFF; 4]));
+
+        assert_eq!(
+            decode_reject_mixed_case("X5GGBH"),
+            Err(Base24Error::DecodeInputLengthInvalid { len: 6 })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_strict() {
+        assert_eq!(decode_strict("X5GGBH7"), Ok(vec![0xFF, 0xFF, 0xFF, 0xFF]));
+        assert_eq!(
+            decode_strict("X5GgBH7"),
+            Err(Base24Error::DecodeUnsupportedCharacter {
+                character: 'g',
+                position: 3
+            })
+        );
+        assert_eq!(
+            decode_strict("ZZZZZZO"),
+            Err(Base24Error::DecodeUnsupportedCharacter {
+                character: 'O',
+                position: 6
+            })
+        );
+        assert_eq!(
+            decode_strict("x5g"),
+            Err(Base24Error::DecodeInputLengthInvalid { len: 3 })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_lenient() {
+        let data = vec![0xFF, 0xFF, 0xFF, 0xFF, 0x88, 0x55, 0x33, 0x11];
+
+        assert_eq!(decode_lenient("X5GG-BH75\nYEAT XA"), Ok(data.clone()));
+        assert_eq!(decode_lenient(" x5ggbh7\t5yeatxa\r\n"), Ok(data.clone()));
+        assert_eq!(
+            Base24::new().decode_lenient_with("X5GG.BH75:YEAT.XA", &['.', ':']),
+            Ok(data)
+        );
+
+        // Alphabet characters given as separators are always removed
+        assert_eq!(
+            Base24::new().decode_lenient_with("ZZZZZZAA", &['A']),
+            Err(Base24Error::DecodeInputLengthInvalid { len: 6 })
+        );
+        assert_eq!(
+            Base24::new().decode_lenient_with("ZZZZAZZZ", &['A']),
+            Ok(vec![0, 0, 0, 0])
+        );
+        assert_eq!(
+            Base24::new().decode_lenient_with("ZZZZZZA-", &['A', '-']),
+            Base24::new().decode_lenient_with("ZZZZZZA", &['A', '-'])
+        );
+
+        assert_eq!(
+            decode_lenient("X5GG-BH"),
+            Err(Base24Error::DecodeInputLengthInvalid { len: 6 })
+        );
+        assert_eq!(
+            decode_lenient("X5GG_BH"),
+            Err(Base24Error::DecodeUnsupportedCharacter {
+                character: '_',
+                position: 4
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_prepare_input() {
+        assert!(matches!(prepare_input("X5GGBH7"), Cow::Borrowed("X5GGBH7")));
+        assert!(matches!(prepare_input(""), Cow::Borrowed("")));
+
+        let prepared = prepare_input("x5gg-BH7\n");
+        assert!(matches!(prepared, Cow::Owned(_)));
+        assert_eq!(prepared, "X5GGBH7");
+
+        assert_eq!(prepare_input("X5GG BH7 O"), "X5GGBH7O");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_repair() {
+        let repaired = repair("0O000I1", DEFAULT_SUBSTITUTIONS);
+        assert_eq!(repaired, "ZZZZZAA");
+        assert_eq!(decode(&repaired), Ok(vec![0, 0, 0, 25]));
+
+        assert_eq!(repair("x5gg-bh7", DEFAULT_SUBSTITUTIONS), "x5gg-bh7");
+        assert_eq!(repair("X5GG?H7", &[('?', 'B')]), "X5GGBH7");
+        assert_eq!(repair("X5GG?H7", &[]), "X5GG?H7");
+        assert_eq!(repair("", DEFAULT_SUBSTITUTIONS), "");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_best_effort() {
+        let data = vec![0xFF, 0xFF, 0xFF, 0xFF, 0x88, 0x55, 0x33, 0x11];
+
+        assert_eq!(
+            decode_best_effort("X5GG-BH75\nYEAT XA"),
+            (data.clone(), vec![(4, '-'), (9, '\n'), (14, ' ')])
+        );
+        assert_eq!(decode_best_effort("x5ggbh75yeatxa"), (data, vec![]));
+        assert_eq!(decode_best_effort(""), (vec![], vec![]));
+
+        assert_eq!(
+            decode_best_effort("ZZZZZ0ZA?Z😘ZZ"),
+            (
+                vec![0, 0, 0, 1, 0, 0, 0, 0],
+                vec![(5, '0'), (8, '?'), (10, '😘')]
+            )
+        );
+        assert_eq!(decode_best_effort("ZZZZZA"), (vec![0, 0, 0, 24], vec![]));
+        assert_eq!(
+            decode_best_effort("YYYYYYY"),
+            (vec![0x11, 0x5F, 0xFF, 0xFF], vec![])
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_grouped() {
+        let data = [
+            0xFF, 0x00, 0x01, 0xFF, 0x00, 0x11, 0x01, 0xFF, 0x01, 0x02, 0x33, 0x99,
+        ];
+
+        let grouped = encode_grouped(&data, 7, '-').expect("error during test encode");
+        assert_eq!(grouped, "XGES63F-ZZ247C7-ZC2ZA6G");
+        assert_eq!(decode_lenient(&grouped), Ok(data.to_vec()));
+
+        let grouped = encode_grouped(&data, 5, ' ').expect("error during test encode");
+        assert_eq!(grouped, "XGES6 3FZZ2 47C7Z C2ZA6 G");
+        assert_eq!(decode_lenient(&grouped), Ok(data.to_vec()));
+
+        assert_eq!(encode_grouped(&data, 0, '-'), encode(data));
+        assert_eq!(encode_grouped(&[], 4, '-'), Ok(String::new()));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_wrapped() {
+        let data: Vec<u8> = (0..=255).collect();
+
+        let wrapped = encode_wrapped(&data, 80).expect("error during test encode");
+        let lines: Vec<&str> = wrapped.lines().collect();
+        assert_eq!(lines.len(), 448_usize.div_ceil(80));
+        assert!(lines[..lines.len() - 1].iter().all(|line| line.len() == 80));
+        assert_eq!(
+            lines.concat(),
+            encode(&data).expect("error during test encode")
+        );
+        assert_eq!(decode_lenient(&wrapped), Ok(data.clone()));
+
+        let wrapped = encode_wrapped(&data[..8], 7).expect("error during test encode");
+        assert_eq!(wrapped, "ZZZBRP2\nZ45E4ZF");
+
+        assert_eq!(encode_wrapped(&data, 0), encode(&data));
+        assert_eq!(
+            encode_wrapped(&data[..3], 80),
+            Err(Base24Error::EncodeInputLengthInvalid { len: 3 })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_spoken() {
+        let data = [0x88, 0x55, 0x33, 0x11, 0xFF, 0xFF, 0xFF, 0xFF];
+
+        let encoded = encode_spoken(&data).expect("error during test encode");
+        assert_eq!(encoded, "5YEATXA4-X5GGBH7C");
+        assert_eq!(decode_spoken(&encoded), Ok(data.to_vec()));
+        assert_eq!(decode_spoken(&encoded.to_lowercase()), Ok(data.to_vec()));
+        assert_eq!(encode_spoken(&[]), Ok(String::new()));
+        assert_eq!(decode_spoken(""), Ok(Vec::new()));
+
+        for idx in (0..encoded.len()).filter(|&idx| idx % 9 != 8) {
+            let mut corrupted = encoded.clone();
+            let replacement = if &encoded[idx..=idx] == "Z" { "A" } else { "Z" };
+            corrupted.replace_range(idx..=idx, replacement);
+            assert_eq!(
+                decode_spoken(&corrupted),
+                Err(Base24Error::SpokenGroupInvalid(idx / 9))
+            );
+        }
+
+        assert_eq!(
+            decode_spoken(&encoded[..15]),
+            Err(Base24Error::SpokenGroupInvalid(1))
+        );
+        assert_eq!(
+            decode_spoken(&encoded.replace('-', "")),
+            Err(Base24Error::SpokenGroupInvalid(0))
+        );
+        assert_eq!(
+            decode_spoken("5YEATXA?"),
+            Err(Base24Error::DecodeUnsupportedCharacter {
+                character: '?',
+                position: 7,
+            })
+        );
+        assert_eq!(
+            encode_spoken(&[1, 2, 3]),
+            Err(Base24Error::EncodeInputLengthInvalid { len: 3 })
+        );
+    }
 
-use errors::Base24Error;
-use std::collections::BTreeMap;
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_checked() {
+        let data = [0x88, 0x55, 0x33, 0x11, 0xFF, 0xFF, 0xFF, 0xFF];
 
-type Result<T> = std::result::Result<T, Base24Error>;
+        let encoded = encode_checked(&data).expect("error during test encode");
+        assert_eq!(encoded.len(), 21);
+        assert!(encoded.starts_with("5YEATXAX5GGBH7"));
+        assert_eq!(decode_checked(&encoded), Ok(data.to_vec()));
+        assert_eq!(decode_checked(&encoded.to_lowercase()), Ok(data.to_vec()));
+        assert_eq!(
+            decode_checked(&encode_checked(&[]).expect("error during test encode")),
+            Ok(Vec::new())
+        );
 
-const ALPHABET: &str = "ZAC2B3EF4GH5TK67P8RS9WXY";
-const ALPHABET_LENGTH: usize = ALPHABET.len();
+        let mut corrupted = encoded.clone();
+        corrupted.replace_range(3..4, "B");
+        assert_eq!(
+            decode_checked(&corrupted),
+            Err(Base24Error::ChecksumMismatch)
+        );
 
-struct Base24 {
-    encode_map: BTreeMap<usize, char>,
-    decode_map: BTreeMap<char, usize>,
-}
+        assert_eq!(
+            decode_checked("ZZZ"),
+            Err(Base24Error::DecodeInputLengthInvalid { len: 3 })
+        );
+        assert_eq!(
+            decode_checked(""),
+            Err(Base24Error::DecodeInputLengthInvalid { len: 0 })
+        );
 
-impl Base24 {
-    pub fn new() -> Base24 {
-        Base24 {
-            encode_map: ALPHABET.char_indices().collect(),
-            decode_map: ALPHABET
-                .char_indices()
-                .map(|(idx, kar)| (kar, idx))
-                .chain(
-                    ALPHABET
-                        .to_lowercase()
-                        .char_indices()
-                        .map(|(idx, kar)| (kar, idx)),
-                )
-                .collect(),
-        }
+        // Errors refer to the whole input, not just the data or checksum part
+        assert_eq!(
+            decode_checked("ZZZZZZAZZZZZZO"),
+            Err(Base24Error::DecodeUnsupportedCharacter {
+                character: 'O',
+                position: 13
+            })
+        );
+        assert_eq!(
+            decode_checked("ZZZZZZAX5GGBH8"),
+            Err(Base24Error::DecodeValueOverflow(1))
+        );
+        assert_eq!(
+            decode_checked("ZZZZZZAZ"),
+            Err(Base24Error::DecodeInputLengthInvalid { len: 8 })
+        );
+        assert_eq!(
+            encode_checked(&[1, 2, 3]),
+            Err(Base24Error::EncodeInputLengthInvalid { len: 3 })
+        );
     }
 
-    pub fn encode(&self, data: &[u8]) -> Result<String> {
-        if data.len() % 4 != 0 {
-            return Err(Base24Error::EncodeInputLengthInvalid);
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_padded() {
+        for len in 0..=17 {
+            let data: Vec<u8> = (1..=len).collect();
+            let encoded = encode_padded(&data).expect("error during test encode");
+
+            assert_eq!(encoded.len(), 7 + usize::from(len).div_ceil(4) * 7);
+            assert_eq!(decode_padded(&encoded), Ok(data));
         }
 
-        let res = data
-            .chunks(4)
-            .map(|chunk| u32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
-            .map(|mut value| {
-                (0..7)
-                    .into_iter()
-                    .map(|_| {
-                        let idx: usize = value as usize % ALPHABET_LENGTH;
-                        value = value / ALPHABET_LENGTH as u32;
+        // The prefix group holds the length, 3
+        assert_eq!(
+            encode_padded(&[1, 2, 3]),
+            Ok(String::from("ZZZZZZ2ZCCYBZZ"))
+        );
 
-                        self.encode_map[&idx].clone()
-                    })
-                    .collect::<Vec<char>>()
-                    .iter()
-                    .rev()
-                    .collect::<String>()
-            })
-            .collect();
+        assert_eq!(decode_padded(""), Err(Base24Error::DecodePaddingInvalid));
+        assert_eq!(
+            decode_padded("ZZZZZZ4"),
+            Err(Base24Error::DecodePaddingInvalid)
+        );
+        assert_eq!(
+            decode_padded("ZZZZZZAZZZZZZA"),
+            Err(Base24Error::DecodePaddingInvalid)
+        );
+        assert_eq!(
+            decode_padded("ZZZZZZZZZZZZZZ"),
+            Err(Base24Error::DecodePaddingInvalid)
+        );
+        assert_eq!(decode_padded("ZZZZZZBZZZZZZA"), Ok(vec![0, 0, 0, 1]));
+    }
 
-        Ok(res)
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_encode_fmt() {
+        let base24 = Base24::new();
+
+        let mut out = String::from("id=");
+        base24
+            .encode_fmt(&[0x88, 0x55, 0x33, 0x11], &mut out)
+            .expect("error during test encode");
+        assert_eq!(out, "id=5YEATXA");
+
+        assert_eq!(
+            base24.encode_fmt(&[1, 2, 3], &mut out),
+            Err(Base24Error::EncodeInputLengthInvalid { len: 3 })
+        );
+        assert_eq!(out, "id=5YEATXA");
     }
 
-    pub fn decode(&self, data: &str) -> Result<Vec<u8>> {
-        let char_vec: Vec<char> = data.chars().collect();
+    #[test]
+    fn test_encode_value() {
+        let base24 = Base24::new();
 
-        if char_vec.len() % 7 != 0 {
-            return Err(Base24Error::DecodeInputLengthInvalid);
-        }
+        let mut value = 0u32;
+        loop {
+            let mut expected = [0u8; 7];
+            let mut remaining = value;
 
-        // Pessimistically check whether the input contains any invalid characters
-        for kar in &char_vec {
-            if !self.decode_map.contains_key(kar) {
-                return Err(Base24Error::DecodeUnsupportedCharacter(kar.clone()));
+            for digit in expected.iter_mut().rev() {
+                *digit = ALPHABET.as_bytes()[remaining as usize % RADIX];
+                remaining /= RADIX as u32;
             }
+
+            assert_eq!(base24.encode_value(value), expected);
+
+            value = match value.checked_add(65_537) {
+                Some(next) => next,
+                None => break,
+            };
         }
 
-        let res = char_vec
-            .chunks(7)
-            .map(|chunks| {
-                chunks.iter().fold(0u32, |acc, kar| {
-                    let idx = self.decode_map.get(kar).unwrap_or_else(|| {
-                        unreachable!("We checked for invalid chars before. Something is wrong!")
-                    });
+        assert_eq!(&base24.encode_value(u32::MAX), b"X5GGBH7");
+    }
 
-                    (ALPHABET_LENGTH as u32) * acc + (*idx as u32)
-                })
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_on_overflow() {
+        let wrap = Base24::with_on_overflow(OnOverflow::Wrap);
+
+        assert_eq!(wrap.decode("X5GGBH8"), Ok(vec![0, 0, 0, 1]));
+        assert_eq!(
+            wrap.decode("ZZZZZZAYYYYYYY"),
+            Ok(vec![0, 0, 0, 1, 0x11, 0x5F, 0xFF, 0xFF])
+        );
+        assert_eq!(wrap.decode_u32("YYYYYYY"), Ok(0x115F_FFFF));
+        assert_eq!(
+            wrap.decode_from_chars("X5GGBH8".chars()),
+            Ok(vec![0, 0, 0, 1])
+        );
+        assert_eq!(wrap.decode_ct("X5GGBH8"), Ok(vec![0, 0, 0, 1]));
+        assert_eq!(
+            wrap.decode_ct("X5GGBHO"),
+            Err(Base24Error::DecodeUnsupportedCharacter {
+                character: 'O',
+                position: 6
             })
-            .flat_map(|value| value.to_be_bytes().to_vec())
-            .collect();
+        );
 
-        Ok(res)
-    }
-}
+        let mut out = [0xFF; 4];
+        assert_eq!(wrap.decode_slice("X5GGBH8", &mut out), Ok(4));
+        assert_eq!(out, [0, 0, 0, 1]);
 
-pub fn encode(data: &[u8]) -> Result<String> {
-    Base24::new().encode(data)
-}
+        let mut decoder = incremental::Decoder::with_base24(wrap.clone());
+        assert_eq!(decoder.update("X5GGBH8"), Ok(vec![0, 0, 0, 1]));
 
-pub fn decode(data: &str) -> Result<Vec<u8>> {
-    Base24::new().decode(data)
-}
+        // Wrapped groups are not canonical
+        assert!(!wrap.is_canonical("X5GGBH8"));
+        assert_eq!(wrap.canonicalize("X5GGBH8"), Ok(String::from("ZZZZZZA")));
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        let reject = builder::Base24Builder::new()
+            .on_overflow(OnOverflow::Reject)
+            .build()
+            .expect("valid configuration");
+        assert_eq!(
+            reject.decode("X5GGBH8"),
+            Err(Base24Error::DecodeValueOverflow(0))
+        );
+        assert_eq!(OnOverflow::default(), OnOverflow::Reject);
+    }
 
     #[test]
-    fn test_all() {
-        // A few hard coded values
-        let values: Vec<(Vec<u8>, _)> = vec![
-            ("00000000", "ZZZZZZZ"),
-            ("000000000000000000000000", "ZZZZZZZZZZZZZZZZZZZZZ"),
-            ("00000001", "ZZZZZZA"),
-            ("000000010000000100000001", "ZZZZZZAZZZZZZAZZZZZZA"),
-            ("00000010", "ZZZZZZP"),
-            ("00000030", "ZZZZZCZ"),
-            ("88553311", "5YEATXA"),
-            ("FFFFFFFF", "X5GGBH7"),
-            ("FFFFFFFFFFFFFFFFFFFFFFFF", "X5GGBH7X5GGBH7X5GGBH7"),
-            ("FFFFFFFFFFFFFFFFFFFFFFFF", "x5ggbh7x5ggbh7x5ggbh7"),
-            ("1234567887654321", "A64KHWZ5WEPAGG"),
-            ("1234567887654321", "a64khwz5wepagg"),
-            ("FF0001FF001101FF01023399", "XGES63FZZ247C7ZC2ZA6G"),
-            ("FF0001FF001101FF01023399", "xges63fzz247c7zc2za6g"),
-            (
-                "25896984125478546598563251452658",
-                "2FC28KTA66WRST4XAHRRCF237S8Z",
-            ),
-            (
-                "25896984125478546598563251452658",
-                "2fc28kta66wrst4xahrrcf237s8z",
-            ),
-            ("00000001", "ZZZZZZA"),
-            ("00000002", "ZZZZZZC"),
-            ("00000004", "ZZZZZZB"),
-            ("00000008", "ZZZZZZ4"),
-            ("00000010", "ZZZZZZP"),
-            ("00000020", "ZZZZZA4"),
-            ("00000040", "ZZZZZCP"),
-            ("00000080", "ZZZZZ34"),
-            ("00000100", "ZZZZZHP"),
-            ("00000200", "ZZZZZW4"),
-            ("00000400", "ZZZZARP"),
-            ("00000800", "ZZZZ2K4"),
-            ("00001000", "ZZZZFCP"),
-            ("00002000", "ZZZZ634"),
-            ("00004000", "ZZZABHP"),
-            ("00008000", "ZZZC4W4"),
-            ("00010000", "ZZZB8RP"),
-            ("00020000", "ZZZG5K4"),
-            ("00040000", "ZZZRYCP"),
-            ("00080000", "ZZAKX34"),
-            ("00100000", "ZZ229HP"),
-            ("00200000", "ZZEFPW4"),
-            ("00400000", "ZZT7GRP"),
-            ("00800000", "ZAAESK4"),
-            ("01000000", "ZCCK7CP"),
-            ("02000000", "ZB32E34"),
-            ("04000000", "Z4HETHP"),
-            ("08000000", "ZP9KZW4"),
-            ("10000000", "AG8CARP"),
-            ("20000000", "CSHB2K4"),
-            ("40000000", "3694FCP"),
-            ("80000000", "53PP634"),
-        ]
-        .iter()
-        .map(|(str_data, b24_str)| {
-            let char_vec: Vec<_> = str_data.chars().collect();
+    #[cfg(feature = "alloc")]
+    fn test_endianness() {
+        let little = Base24::with_endianness(Endianness::Little);
+        let data = [0x11, 0x33, 0x55, 0x88, 0xFF, 0xFF, 0xFF, 0xFF];
 
-            let data: Vec<u8> = char_vec
-                .chunks(2)
-                .map(|chunk| {
-                    let byte_str = format!("{}{}", chunk[0], chunk[1]);
+        assert_eq!(little.encode(&data), Ok("5YEATXAX5GGBH7".to_string()));
+        assert_eq!(little.decode("5YEATXAX5GGBH7"), Ok(data.to_vec()));
+        assert_eq!(little.decode_u32("ZZZZZZA"), Ok(1));
+        assert_eq!(
+            little.decode_u64(&little.encode_u64(0x1234567887654321)),
+            Ok(0x1234567887654321)
+        );
 
-                    u8::from_str_radix(&byte_str, 16)
-                })
-                .filter_map(|res| res.ok())
-                .collect();
+        let big = Base24::with_endianness(Endianness::Big);
+        assert_eq!(big.encode(&data), encode(data));
 
-            (data, b24_str.to_string())
-        })
-        .collect();
+        for base24 in &[big, little] {
+            let data: Vec<u8> = (0..=255).collect();
+            let encoded = base24.encode(&data).expect("error during test encode");
 
-        for (data, b24_str) in values {
-            let decoded = decode(&b24_str).expect("error during test decode");
-            assert_eq!(decoded, data);
+            assert_eq!(base24.decode(&encoded), Ok(data.clone()));
             assert_eq!(
-                encode(&decoded).expect("error during test encode"),
-                b24_str.to_uppercase()
+                base24
+                    .encode_padded(&data[..5])
+                    .and_then(|encoded| base24.decode_padded(&encoded)),
+                Ok(data[..5].to_vec())
             );
         }
     }
 
     #[test]
-    fn random_test() {
+    #[cfg(feature = "alloc")]
+    fn test_aliases() {
+        let base24 = Base24::with_aliases(&[('0', 'Z'), ('O', 'Z'), ('o', 'z'), ('1', 'A')])
+            .expect("valid aliases");
+
+        assert_eq!(base24.decode("0Oo0001"), Ok(vec![0, 0, 0, 1]));
+        assert_eq!(base24.decode_ct("0Oo0001"), Ok(vec![0, 0, 0, 1]));
+        assert_eq!(base24.decode_lenient("0000-001"), Ok(vec![0, 0, 0, 1]));
+        assert_eq!(base24.normalize("x5gg-o01"), "X5GG-ZZA");
+        assert!(base24.is_canonical("ZZZZZZA"));
+        assert!(base24.is_canonical("zzzzzza"));
+        assert!(!base24.is_canonical("0000000"));
+        assert!(!base24.is_canonical("ZZZZZZ1"));
+        assert_eq!(
+            base24.decode_strict("000000A"),
+            Err(Base24Error::DecodeUnsupportedCharacter {
+                character: '0',
+                position: 0
+            })
+        );
+        assert_eq!(
+            decode("000000A"),
+            Err(Base24Error::DecodeUnsupportedCharacter {
+                character: '0',
+                position: 0
+            })
+        );
+
+        assert!(matches!(
+            Base24::with_aliases(&[('Z', 'A')]),
+            Err(Base24Error::InvalidAlias('Z'))
+        ));
+        assert!(matches!(
+            Base24::with_aliases(&[('0', 'O')]),
+            Err(Base24Error::InvalidAlias('0'))
+        ));
+        assert!(matches!(
+            Base24::with_aliases(&[('Ø', 'Z')]),
+            Err(Base24Error::InvalidAlias('Ø'))
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_debug_and_display() {
+        assert_eq!(
+            format!("{:?}", Base24::new()),
+            "Base24 { alphabet: \"ZAC2B3EF4GH5TK67P8RS9WXY\", endianness: Big, on_overflow: Reject, aliases: {} }"
+        );
+        assert_eq!(
+            Base24::new().to_string(),
+            "base24(ZAC2B3EF4GH5TK67P8RS9WXY)"
+        );
+        assert_eq!(
+            Base24::with_endianness(Endianness::Little).to_string(),
+            "base24(ZAC2B3EF4GH5TK67P8RS9WXY, little-endian)"
+        );
+        assert_eq!(
+            Base24::with_on_overflow(OnOverflow::Wrap).to_string(),
+            "base24(ZAC2B3EF4GH5TK67P8RS9WXY, wrap)"
+        );
+        assert_eq!(
+            builder::Base24Builder::new()
+                .endianness(Endianness::Little)
+                .on_overflow(OnOverflow::Wrap)
+                .aliases(&[('0', 'Z')])
+                .build()
+                .expect("valid configuration")
+                .to_string(),
+            "base24(ZAC2B3EF4GH5TK67P8RS9WXY, little-endian, wrap, aliases 0:Z)"
+        );
+        assert_eq!(
+            Base24::new_ordered().to_string(),
+            "base24(23456789ABCEFGHKPRSTWXYZ)"
+        );
+
+        let aliased = Base24::with_aliases(&[('0', 'Z'), ('O', 'z')]).expect("valid aliases");
+        assert_eq!(
+            format!("{:?}", aliased),
+            "Base24 { alphabet: \"ZAC2B3EF4GH5TK67P8RS9WXY\", endianness: Big, on_overflow: Reject, aliases: {'0': 'Z', 'O': 'Z'} }"
+        );
+        assert_eq!(
+            aliased.to_string(),
+            "base24(ZAC2B3EF4GH5TK67P8RS9WXY, aliases 0:Z O:Z)"
+        );
+
+        // Other case forms of alphabet characters are not aliases
+        let lower = builder::Base24Builder::new()
+            .output_case(Case::Lower)
+            .endianness(Endianness::Little)
+            .build()
+            .expect("valid configuration");
+        assert_eq!(
+            lower.to_string(),
+            "base24(zac2b3ef4gh5tk67p8rs9wxy, little-endian)"
+        );
+        let mixed = Base24::with_alphabet("ABCDEFGHIJKLMNOPQRSTUVWa").expect("valid alphabet");
+        assert_eq!(mixed.to_string(), "base24(ABCDEFGHIJKLMNOPQRSTUVWa)");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_custom_alphabet() {
+        let base24 = Base24::with_alphabet("0123456789ABCDEFGHJKMNPQ").expect("valid alphabet");
+
+        assert_eq!(base24.encode(&[0, 0, 0, 0]), Ok("0000000".to_string()));
+        assert_eq!(base24.encode(&[0, 0, 0, 23]), Ok("000000Q".to_string()));
+        assert_eq!(base24.decode("000000q"), Ok(vec![0, 0, 0, 23]));
+
+        let data = [0xFF, 0x00, 0x01, 0xFF, 0x00, 0x11, 0x01, 0xFF];
+        let encoded = base24.encode(&data).expect("error during test encode");
+        assert_eq!(base24.decode(&encoded), Ok(data.to_vec()));
+
+        assert_eq!(base24.alphabet(), "0123456789ABCDEFGHJKMNPQ");
+        assert_eq!(Base24::new().alphabet(), ALPHABET);
+        assert_eq!(
+            Base24::with_alphabet(ALPHABET).map(|base24| base24.encode(&data)),
+            Ok(encode(data))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_ordered_alphabet() {
         use rand::distributions::Standard;
         use rand::{thread_rng, Rng};
 
-        let rng = thread_rng();
+        let base24 = Base24::new_ordered();
+        assert_eq!(base24.alphabet(), ORDERED_ALPHABET);
+        assert_eq!(base24.encode(&[0, 0, 0, 0]), Ok("2222222".to_string()));
+        assert_eq!(base24.encode(&[0xFF; 4]), Ok("YEBB6CK".to_string()));
 
-        for _ in 0..100 {
-            let original_data: Vec<u8> = rng.sample_iter(Standard).take(64).collect();
+        let mut rng = thread_rng();
+        let mut data: Vec<Vec<u8>> = (0..100)
+            .map(|_| (&mut rng).sample_iter(Standard).take(8).collect())
+            .collect();
+        let mut encoded: Vec<String> = data
+            .iter()
+            .map(|bytes| base24.encode(bytes).expect("error during test encode"))
+            .collect();
 
-            let encoded_data = encode(&original_data).expect("error during test encode");
-            let decoded_data = decode(&encoded_data).expect("error during test decode");
+        data.sort();
+        encoded.sort();
 
-            assert_eq!(decoded_data, original_data);
+        for (bytes, encoded) in data.iter().zip(&encoded) {
+            assert_eq!(
+                &base24.decode(encoded).expect("error during test decode"),
+                bytes
+            );
         }
     }
 
     #[test]
+    fn test_default_tables() {
+        let base24 = Base24::with_alphabet(ALPHABET).expect("valid alphabet");
+
+        assert_eq!(DEFAULT_ENCODE_MAP, base24.encode_map);
+        assert_eq!(DEFAULT_DECODE_MAP[..], base24.decode_map[..]);
+        assert_eq!(Base24::new().alphabet(), ALPHABET);
+    }
+
+    #[test]
+    fn test_validate_alphabet() {
+        assert!(validate_alphabet(ALPHABET));
+        assert!(validate_alphabet("0123456789ABCDEFGHJKMNPQ"));
+        assert!(!validate_alphabet("ZAC2B3EF4GH5TK67P8RS9WX"));
+        assert!(!validate_alphabet("ZAC2B3EF4GH5TK67P8RS9WXYZ"));
+        assert!(!validate_alphabet("ZAC2B3EF4GH5TK67P8RS9WXZ"));
+        assert!(!validate_alphabet("ZAC2B3EF4GH5TK67P8RS9Wé"));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_decode_with_alphabet() {
+        let alphabet = "0123456789ABCDEFGHJKMNPQ";
+
+        assert_eq!(
+            Base24::decode_with_alphabet("000000q", alphabet),
+            Ok(vec![0, 0, 0, 23])
+        );
+        assert_eq!(
+            Base24::decode_with_alphabet("X5GGBH7", ALPHABET),
+            decode("X5GGBH7")
+        );
+        assert_eq!(
+            Base24::decode_with_alphabet("X5GGBH7", alphabet),
+            Err(Base24Error::DecodeUnsupportedCharacter {
+                character: 'X',
+                position: 0
+            })
+        );
+        assert_eq!(
+            Base24::decode_with_alphabet("0000000", "0123"),
+            Err(Base24Error::InvalidAlphabet)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_invalid_alphabet() {
+        assert!(matches!(
+            Base24::with_alphabet("ZAC2B3EF4GH5TK67P8RS9WX"),
+            Err(Base24Error::InvalidAlphabet)
+        ));
+        assert!(matches!(
+            Base24::with_alphabet("ZAC2B3EF4GH5TK67P8RS9WXYZ"),
+            Err(Base24Error::InvalidAlphabet)
+        ));
+        assert!(matches!(
+            Base24::with_alphabet("ZZC2B3EF4GH5TK67P8RS9WXY"),
+            Err(Base24Error::InvalidAlphabet)
+        ));
+        assert!(matches!(
+            Base24::with_alphabet("ZÄC2B3EF4GH5TK67P8RS9WXY"),
+            Err(Base24Error::InvalidAlphabet)
+        ));
+
+        // 24 chars, but more than 24 bytes
+        assert!(matches!(
+            Base24::with_alphabet("ZAC2B3EF4GH5TK67P8RS9WX😘"),
+            Err(Base24Error::InvalidAlphabet)
+        ));
+        // 24 bytes, but fewer than 24 chars
+        assert!(matches!(
+            Base24::with_alphabet("ZAC2B3EF4GH5TK67P8RS😘"),
+            Err(Base24Error::InvalidAlphabet)
+        ));
+        assert_eq!(
+            Base24Error::InvalidAlphabet.to_string(),
+            "Alphabet must consist of exactly 24 unique ASCII characters"
+        );
+    }
+
+    #[test]
+    fn test_is_valid() {
+        assert!(is_valid(""));
+        assert!(is_valid("X5GGBH7"));
+        assert!(is_valid("x5ggbh7ZZZZZZA"));
+        assert!(!is_valid("X5GGBH"));
+        assert!(!is_valid("ZZZZZZO"));
+        assert!(!is_valid("ABC😘EFG"));
+        assert!(!is_valid("YYYYYYY"));
+        assert!(!is_valid("ZZZZZZAX5GGBH8"));
+        assert!(Base24::with_on_overflow(OnOverflow::Wrap).is_valid("YYYYYYY"));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_case_insensitivity() {
+        assert!(eq_ignore_case("x5ggbh7", "X5GGBH7"));
+        assert!(eq_ignore_case("X5gGbH7", "x5GgBh7"));
+        assert!(!eq_ignore_case("X5GGBH7", "X5GGBH"));
+        assert!(!eq_ignore_case("X5GGBH7", "ZZZZZZA"));
+
+        assert_eq!(normalize("x5ggbh7"), "X5GGBH7");
+        assert_eq!(normalize("x5gg-bh7 o"), "X5GG-BH7 o");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
     fn test_failures() {
         let test_data: [u8; 5] = [1, 2, 3, 4, 5];
 
         assert_eq!(
-            encode(&test_data),
-            Err(Base24Error::EncodeInputLengthInvalid)
+            encode(test_data),
+            Err(Base24Error::EncodeInputLengthInvalid { len: 5 })
+        );
+
+        assert_eq!(
+            encode_lower(&test_data),
+            Err(Base24Error::EncodeInputLengthInvalid { len: 5 })
+        );
+
+        // The lowercase form of 'A' is a digit of its own
+        let base24 = Base24::with_alphabet("ABCDEFGHIJKLMNOPQRSTUVWa").expect("valid alphabet");
+        assert_eq!(
+            base24.encode_lower(&[0, 0, 0, 23]),
+            Err(Base24Error::InvalidAlphabet)
         );
 
         let test_data: &str = "ZZZ";
 
         assert_eq!(
-            decode(&test_data),
-            Err(Base24Error::DecodeInputLengthInvalid)
+            decode(test_data),
+            Err(Base24Error::DecodeInputLengthInvalid { len: 3 })
+        );
+        assert_eq!(
+            Base24Error::DecodeInputLengthInvalid { len: 3 }.to_string(),
+            "Input length 3 is not a multiple of 7 chars"
         );
 
         let test_data: &str = "ZZZZZZO";
 
         assert_eq!(
-            decode(&test_data),
-            Err(Base24Error::DecodeUnsupportedCharacter('O'))
+            decode(test_data),
+            Err(Base24Error::DecodeUnsupportedCharacter {
+                character: 'O',
+                position: 6
+            })
+        );
+
+        assert_eq!(
+            decode(test_data).map_err(|err| err.to_string()),
+            Err("Unsupported character 'O' at position 6".to_string())
         );
 
+        let test_data: &str = "X5GGBH8";
+
+        assert_eq!(decode(test_data), Err(Base24Error::DecodeValueOverflow(0)));
+
+        let test_data: &str = "ZZZZZZAYYYYYYY";
+
+        assert_eq!(decode(test_data), Err(Base24Error::DecodeValueOverflow(1)));
+
+        // Errors are reported in input order
+        let test_data: &str = "X5GGBH8ZZZZZZO";
+
+        assert_eq!(decode(test_data), Err(Base24Error::DecodeValueOverflow(0)));
+
         let test_data: &str = "ABC😘EFG";
 
         assert_eq!(
-            decode(&test_data),
-            Err(Base24Error::DecodeUnsupportedCharacter('😘'))
+            decode(test_data),
+            Err(Base24Error::DecodeUnsupportedCharacter {
+                character: '😘',
+                position: 3
+            })
         );
     }
 }
@@ -0,0 +1,89 @@
+use std::env;
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::process;
+
+const USAGE: &str = "Usage: base24 [--decode] [--lower] [FILE]
+
+Encodes FILE, or standard input, to base24 and writes it to standard output.
+
+Options:
+  -d, --decode  Decode base24 input instead of encoding it
+  -l, --lower   Emit lowercase characters when encoding
+  -h, --help    Print this help";
+
+struct Options {
+    decode: bool,
+    lower: bool,
+    path: Option<String>,
+}
+
+fn parse_args() -> Result<Options, String> {
+    let mut options = Options {
+        decode: false,
+        lower: false,
+        path: None,
+    };
+
+    for arg in env::args().skip(1) {
+        match arg.as_str() {
+            "-d" | "--decode" => options.decode = true,
+            "-l" | "--lower" => options.lower = true,
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                process::exit(0);
+            }
+            _ if arg.starts_with('-') && arg != "-" => {
+                return Err(format!("unknown option {:?}", arg));
+            }
+            _ if options.path.is_some() => return Err("too many arguments".to_string()),
+            _ => options.path = Some(arg),
+        }
+    }
+
+    Ok(options)
+}
+
+fn run(options: &Options) -> Result<(), String> {
+    let mut input = Vec::new();
+
+    match options.path.as_deref() {
+        None | Some("-") => io::stdin().read_to_end(&mut input),
+        Some(path) => File::open(path).and_then(|mut file| file.read_to_end(&mut input)),
+    }
+    .map_err(|err| err.to_string())?;
+
+    let output = if options.decode {
+        let text = String::from_utf8(input).map_err(|_| "input is not valid UTF-8".to_string())?;
+
+        base24::decode_lenient(&text).map_err(|err| err.to_string())?
+    } else {
+        let mut encoded = if options.lower {
+            base24::encode_lower(&input)
+        } else {
+            base24::encode(&input)
+        }
+        .map_err(|err| err.to_string())?;
+        encoded.push('\n');
+
+        encoded.into_bytes()
+    };
+
+    let mut stdout = io::stdout();
+    stdout
+        .write_all(&output)
+        .and_then(|_| stdout.flush())
+        .map_err(|err| err.to_string())
+}
+
+fn main() {
+    let options = parse_args().unwrap_or_else(|err| {
+        eprintln!("base24: {}\n\n{}", err, USAGE);
+        process::exit(2);
+    });
+
+    if let Err(err) = run(&options) {
+        eprintln!("base24: {}", err);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,81 @@
+//! Serializes byte fields as base24 text, for use with `#[serde(with = "...")]`:
+//!
+//! ```
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Key {
+//!     #[serde(with = "base24::serde_base24")]
+//!     data: Vec<u8>,
+//! }
+//! ```
+//!
+//! Serializing fails if the number of bytes is not a multiple of 4, and deserializing
+//! reports decode errors through [`serde::de::Error::custom`].
+
+use alloc::vec::Vec;
+use core::fmt;
+use serde::{de, Deserializer, Serializer};
+
+/// Serializes `data` as a base24 string.
+pub fn serialize<T, S>(data: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: AsRef<[u8]> + ?Sized,
+    S: Serializer,
+{
+    let encoded = crate::encode(data).map_err(serde::ser::Error::custom)?;
+
+    serializer.serialize_str(&encoded)
+}
+
+/// Deserializes bytes from a base24 string.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+    deserializer.deserialize_str(Base24Visitor)
+}
+
+struct Base24Visitor;
+
+impl de::Visitor<'_> for Base24Visitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a base24 string")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Vec<u8>, E> {
+        crate::decode(v).map_err(E::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{string::ToString, vec, vec::Vec};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Key {
+        #[serde(with = "crate::serde_base24")]
+        data: Vec<u8>,
+    }
+
+    #[test]
+    fn test_serde_base24() {
+        let key = Key {
+            data: vec![0x88, 0x55, 0x33, 0x11],
+        };
+
+        let json = serde_json::to_string(&key).expect("error during test serialize");
+        assert_eq!(json, r#"{"data":"5YEATXA"}"#);
+        assert_eq!(
+            serde_json::from_str::<Key>(r#"{"data":"5yeatxa"}"#).ok(),
+            Some(key)
+        );
+
+        assert!(serde_json::to_string(&Key {
+            data: vec![1, 2, 3]
+        })
+        .map_err(|err| err.to_string())
+        .unwrap_err()
+        .starts_with("Input length 3 is not"));
+        assert!(serde_json::from_str::<Key>(r#"{"data":"ZZZ"}"#).is_err());
+        assert!(serde_json::from_str::<Key>(r#"{"data":[1, 2]}"#).is_err());
+    }
+}
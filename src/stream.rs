@@ -0,0 +1,356 @@
+use crate::errors::Base24Error;
+use crate::{Base24, BYTES_PER_GROUP, CHARS_PER_GROUP};
+use std::io::{self, Read, Write};
+
+/// Streaming encoder wrapping an [`io::Write`] sink.
+///
+/// Every complete 4-byte group written to it is encoded and passed on to the inner
+/// writer, while a trailing partial group is retained until more data arrives.
+pub struct Base24Writer<W: Write> {
+    base24: Base24,
+    inner: W,
+    pending: Vec<u8>,
+    encoded: String,
+    written: usize,
+}
+
+impl<W: Write> Base24Writer<W> {
+    pub fn new(inner: W) -> Base24Writer<W> {
+        Base24Writer::with_base24(Base24::new(), inner)
+    }
+
+    pub fn with_base24(base24: Base24, inner: W) -> Base24Writer<W> {
+        Base24Writer {
+            base24,
+            inner,
+            pending: Vec::with_capacity(BYTES_PER_GROUP),
+            encoded: String::new(),
+            written: 0,
+        }
+    }
+
+    /// Flushes the inner writer and returns it.
+    ///
+    /// Fails if the total number of bytes written is not a multiple of 4.
+    pub fn finish(mut self) -> io::Result<W> {
+        if !self.pending.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                Base24Error::EncodeInputLengthInvalid { len: self.written },
+            ));
+        }
+
+        self.inner.flush()?;
+
+        Ok(self.inner)
+    }
+
+    fn write_groups(&mut self, data: &[u8]) -> io::Result<()> {
+        self.base24
+            .encode_into(data, &mut self.encoded)
+            .unwrap_or_else(|_| {
+                unreachable!("Only complete groups are encoded. Something is wrong!")
+            });
+
+        self.inner.write_all(self.encoded.as_bytes())
+    }
+}
+
+impl<W: Write> Write for Base24Writer<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut data = buf;
+
+        if !self.pending.is_empty() {
+            let missing = (BYTES_PER_GROUP - self.pending.len()).min(data.len());
+            self.pending.extend_from_slice(&data[..missing]);
+            data = &data[missing..];
+
+            if self.pending.len() < BYTES_PER_GROUP {
+                self.written += buf.len();
+                return Ok(buf.len());
+            }
+
+            let group = [
+                self.pending[0],
+                self.pending[1],
+                self.pending[2],
+                self.pending[3],
+            ];
+            self.pending.clear();
+            self.write_groups(&group)?;
+        }
+
+        let complete = data.len() - data.len() % BYTES_PER_GROUP;
+        self.write_groups(&data[..complete])?;
+        self.pending.extend_from_slice(&data[complete..]);
+        self.written += buf.len();
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Streaming decoder wrapping an [`io::Read`] source of base24 text.
+///
+/// Characters are read from the inner reader and decoded as soon as a complete 7-char
+/// group is available. Invalid characters and a trailing partial group at the end of
+/// the input surface as [`io::ErrorKind::InvalidData`] errors.
+pub struct Base24Reader<R: Read> {
+    base24: Base24,
+    inner: R,
+    groups: GroupBuffer,
+    decoded: Vec<u8>,
+    position: usize,
+}
+
+impl<R: Read> Base24Reader<R> {
+    pub fn new(inner: R) -> Base24Reader<R> {
+        Base24Reader::with_base24(Base24::new(), inner)
+    }
+
+    pub fn with_base24(base24: Base24, inner: R) -> Base24Reader<R> {
+        Base24Reader {
+            base24,
+            inner,
+            groups: GroupBuffer::new(),
+            decoded: Vec::new(),
+            position: 0,
+        }
+    }
+
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Reads from the inner reader until at least one group was decoded. Returns `false`
+    /// at the end of the input.
+    fn fill(&mut self) -> io::Result<bool> {
+        let mut buf = [0u8; 1024];
+
+        self.decoded.clear();
+        self.position = 0;
+
+        while self.decoded.is_empty() {
+            let len = self.inner.read(&mut buf)?;
+
+            if len == 0 {
+                self.groups.finish()?;
+
+                return Ok(false);
+            }
+
+            self.groups
+                .decode(&self.base24, &buf[..len], &mut self.decoded)?;
+        }
+
+        Ok(true)
+    }
+}
+
+impl<R: Read> Read for Base24Reader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.position == self.decoded.len() && !self.fill()? {
+            return Ok(0);
+        }
+
+        let len = buf.len().min(self.decoded.len() - self.position);
+        buf[..len].copy_from_slice(&self.decoded[self.position..self.position + len]);
+        self.position += len;
+
+        Ok(len)
+    }
+}
+
+/// Text read by a streaming decoder that does not yet form a complete group.
+pub(crate) struct GroupBuffer {
+    pending: String,
+    consumed: usize,
+}
+
+impl GroupBuffer {
+    pub(crate) fn new() -> GroupBuffer {
+        GroupBuffer {
+            pending: String::with_capacity(CHARS_PER_GROUP),
+            consumed: 0,
+        }
+    }
+
+    /// Appends `input` to the pending text and decodes its complete groups into
+    /// `decoded`, replacing its previous contents.
+    pub(crate) fn decode(
+        &mut self,
+        base24: &Base24,
+        input: &[u8],
+        decoded: &mut Vec<u8>,
+    ) -> io::Result<()> {
+        for &byte in input {
+            let kar = char::from(byte);
+
+            if base24.decode_char(kar).is_none() {
+                return Err(invalid_data(Base24Error::DecodeUnsupportedCharacter {
+                    character: kar,
+                    position: self.consumed,
+                }));
+            }
+
+            self.pending.push(kar);
+            self.consumed += 1;
+        }
+
+        // Pending text starts at a group boundary, so this counts the groups before it
+        let groups = (self.consumed - self.pending.len()) / CHARS_PER_GROUP;
+        let complete = self.pending.len() - self.pending.len() % CHARS_PER_GROUP;
+        base24
+            .decode_into(&self.pending[..complete], decoded)
+            .map_err(|err| match err {
+                Base24Error::DecodeValueOverflow(group) => {
+                    Base24Error::DecodeValueOverflow(groups + group)
+                }
+                err => err,
+            })
+            .map_err(invalid_data)?;
+        self.pending.drain(..complete);
+
+        Ok(())
+    }
+
+    /// Fails if the input ended within a group.
+    pub(crate) fn finish(&self) -> io::Result<()> {
+        if self.pending.is_empty() {
+            Ok(())
+        } else {
+            Err(invalid_data(Base24Error::TrailingPartialGroup {
+                chars: self.pending.len(),
+            }))
+        }
+    }
+}
+
+fn invalid_data(err: Base24Error) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, err)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_writer() {
+        let data: Vec<u8> = (0..=255).collect();
+
+        let mut writer = Base24Writer::new(Vec::new());
+        for piece in data.chunks(3) {
+            writer.write_all(piece).expect("error during test write");
+        }
+        let encoded = writer.finish().expect("error during test finish");
+
+        assert_eq!(
+            encoded,
+            crate::encode(&data)
+                .expect("error during test encode")
+                .into_bytes()
+        );
+    }
+
+    #[test]
+    fn test_writer_copy() {
+        let data = [0xFF, 0xFF, 0xFF, 0xFF, 0x88, 0x55, 0x33, 0x11];
+
+        let mut writer = Base24Writer::new(Vec::new());
+        io::copy(&mut &data[..], &mut writer).expect("error during test copy");
+
+        assert_eq!(
+            writer.finish().expect("error during test finish"),
+            b"X5GGBH75YEATXA"
+        );
+    }
+
+    #[test]
+    fn test_writer_partial_group() {
+        let mut writer = Base24Writer::new(Vec::new());
+        writer
+            .write_all(&[1, 2, 3, 4, 5])
+            .expect("error during test write");
+
+        let err = writer.finish().expect_err("partial group must fail");
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(
+            err.into_inner()
+                .and_then(|err| err.downcast::<Base24Error>().ok())
+                .map(|err| *err),
+            Some(Base24Error::EncodeInputLengthInvalid { len: 5 })
+        );
+    }
+
+    #[test]
+    fn test_reader() {
+        let data: Vec<u8> = (0..=255).collect();
+        let encoded = crate::encode(&data).expect("error during test encode");
+
+        let mut decoded = Vec::new();
+        Base24Reader::new(encoded.as_bytes())
+            .read_to_end(&mut decoded)
+            .expect("error during test read");
+
+        assert_eq!(decoded, data);
+    }
+
+    #[test]
+    fn test_reader_small_reads() {
+        let mut reader = Base24Reader::new(&b"x5ggbh75YEATXA"[..]);
+        let mut decoded = Vec::new();
+        let mut byte = [0u8; 1];
+
+        while reader.read(&mut byte).expect("error during test read") == 1 {
+            decoded.push(byte[0]);
+        }
+
+        assert_eq!(decoded, [0xFF, 0xFF, 0xFF, 0xFF, 0x88, 0x55, 0x33, 0x11]);
+    }
+
+    #[test]
+    fn test_reader_failures() {
+        let mut decoded = Vec::new();
+
+        let err = Base24Reader::new(&b"ZZZZZZO"[..])
+            .read_to_end(&mut decoded)
+            .expect_err("invalid character must fail");
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(
+            err.into_inner()
+                .and_then(|err| err.downcast::<Base24Error>().ok())
+                .map(|err| *err),
+            Some(Base24Error::DecodeUnsupportedCharacter {
+                character: 'O',
+                position: 6
+            })
+        );
+
+        let err = Base24Reader::new(&b"ZZZZZZAZZ"[..])
+            .read_to_end(&mut decoded)
+            .expect_err("partial group must fail");
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(
+            err.into_inner()
+                .and_then(|err| err.downcast::<Base24Error>().ok())
+                .map(|err| *err),
+            Some(Base24Error::TrailingPartialGroup { chars: 2 })
+        );
+
+        // Groups are counted from the start of the stream, not of the current batch
+        let mut input = "ZZZZZZA".repeat(300);
+        input.push_str("X5GGBH8");
+        let err = Base24Reader::new(input.as_bytes())
+            .read_to_end(&mut decoded)
+            .expect_err("overflowing group must fail");
+        assert_eq!(
+            err.into_inner()
+                .and_then(|err| err.downcast::<Base24Error>().ok())
+                .map(|err| *err),
+            Some(Base24Error::DecodeValueOverflow(300))
+        );
+    }
+}
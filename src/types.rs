@@ -0,0 +1,243 @@
+use crate::errors::Base24Error;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::fmt;
+use core::ops::Deref;
+use core::str::FromStr;
+
+/// Owned bytes that parse from and display as base24.
+///
+/// The number of held bytes is always a multiple of 4, so formatting cannot fail.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Base24String(Vec<u8>);
+
+impl Base24String {
+    /// Takes ownership of `bytes`, failing if their number is not a multiple of 4.
+    pub fn try_from_bytes(bytes: Vec<u8>) -> Result<Base24String, Base24Error> {
+        crate::encoded_len(bytes.len())
+            .ok_or(Base24Error::EncodeInputLengthInvalid { len: bytes.len() })?;
+
+        Ok(Base24String(bytes))
+    }
+
+    /// Alias for [`Base24String::try_from_bytes`].
+    ///
+    /// This is fallible as well, as holding a length that is not a multiple of 4 would
+    /// make formatting fail.
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Base24String, Base24Error> {
+        Base24String::try_from_bytes(bytes)
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.0
+    }
+}
+
+impl FromStr for Base24String {
+    type Err = Base24Error;
+
+    fn from_str(s: &str) -> Result<Base24String, Base24Error> {
+        crate::decode(s).map(Base24String)
+    }
+}
+
+impl TryFrom<&str> for Base24String {
+    type Error = Base24Error;
+
+    fn try_from(s: &str) -> Result<Base24String, Base24Error> {
+        s.parse()
+    }
+}
+
+/// Copies the bytes, failing if their number is not a multiple of 4.
+impl TryFrom<&[u8]> for Base24String {
+    type Error = Base24Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Base24String, Base24Error> {
+        Base24String::try_from_bytes(bytes.to_vec())
+    }
+}
+
+impl fmt::Display for Base24String {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        crate::with_default(|base24| base24.encode_fmt(&self.0, f)).map_err(|err| match err {
+            Base24Error::OutputWriteFailed => fmt::Error,
+            _ => unreachable!("The length is checked on construction. Something is wrong!"),
+        })
+    }
+}
+
+/// Bytes that serialize as base24 text, available with the `serde` feature.
+///
+/// Serializing fails if the number of bytes is not a multiple of 4, and deserializing
+/// reports decode errors, such as a length that is not a multiple of 7 chars, through
+/// [`serde::de::Error::custom`].
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Base24Bytes(pub Vec<u8>);
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Base24Bytes {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::serde_base24::serialize(&self.0, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Base24Bytes {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Base24Bytes, D::Error> {
+        crate::serde_base24::deserialize(deserializer).map(Base24Bytes)
+    }
+}
+
+/// An encoded base24 string, usable as both `str` and `[u8]`.
+///
+/// Returned by [`Base24::encode_owned`](crate::Base24::encode_owned). The encoding is
+/// ASCII, so the buffer serves both views without conversion.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Encoded(String);
+
+impl Encoded {
+    pub(crate) fn new(encoded: String) -> Encoded {
+        Encoded(encoded)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl Deref for Encoded {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for Encoded {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<[u8]> for Encoded {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_bytes()
+    }
+}
+
+impl fmt::Display for Encoded {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{format, string::ToString, vec};
+
+    #[test]
+    fn test_base24_string() {
+        let value: Base24String = "x5ggbh7ZZZZZZA".parse().expect("error during test parse");
+
+        assert_eq!(value.as_bytes(), [0xFF, 0xFF, 0xFF, 0xFF, 0, 0, 0, 1]);
+        assert_eq!(value.to_string(), "X5GGBH7ZZZZZZA");
+        assert_eq!(
+            Base24String::try_from_bytes(vec![0x88, 0x55, 0x33, 0x11]).map(|s| s.to_string()),
+            Ok(String::from("5YEATXA"))
+        );
+        assert_eq!(
+            Base24String::try_from_bytes(vec![1, 2, 3]),
+            Err(Base24Error::EncodeInputLengthInvalid { len: 3 })
+        );
+        assert_eq!(
+            Base24String::from_bytes(vec![0x88, 0x55, 0x33, 0x11]),
+            Base24String::try_from_bytes(vec![0x88, 0x55, 0x33, 0x11])
+        );
+        assert_eq!(
+            Base24String::from_bytes(vec![1, 2, 3]),
+            Err(Base24Error::EncodeInputLengthInvalid { len: 3 })
+        );
+
+        assert_eq!(
+            "ZZZZZZO".parse::<Base24String>(),
+            Err(Base24Error::DecodeUnsupportedCharacter {
+                character: 'O',
+                position: 6
+            })
+        );
+    }
+
+    #[test]
+    fn test_base24_string_try_from() {
+        let value = Base24String::try_from("5yeatxa").expect("error during test conversion");
+        assert_eq!(value.as_bytes(), [0x88, 0x55, 0x33, 0x11]);
+
+        let bytes: &[u8] = &[0x88, 0x55, 0x33, 0x11];
+        assert_eq!(Base24String::try_from(bytes), Ok(value));
+
+        let bytes: &[u8] = &[1, 2, 3];
+        assert_eq!(
+            Base24String::try_from(bytes),
+            Err(Base24Error::EncodeInputLengthInvalid { len: 3 })
+        );
+        assert_eq!(
+            Base24String::try_from("ZZZ"),
+            Err(Base24Error::DecodeInputLengthInvalid { len: 3 })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_base24_bytes() {
+        let value = Base24Bytes(vec![0x88, 0x55, 0x33, 0x11]);
+
+        let json = serde_json::to_string(&value).expect("error during test serialize");
+        assert_eq!(json, "\"5YEATXA\"");
+        assert_eq!(
+            serde_json::from_str::<Base24Bytes>("\"5yeatxa\"").ok(),
+            Some(value)
+        );
+
+        assert_eq!(
+            serde_json::to_string(&Base24Bytes(vec![1, 2, 3])).map_err(|err| err.to_string()),
+            Err(Base24Error::EncodeInputLengthInvalid { len: 3 }.to_string())
+        );
+        assert_eq!(
+            serde_json::from_str::<Base24Bytes>("\"ZZZ\"")
+                .map_err(|err| err.to_string().starts_with("Input length 3 is not")),
+            Err(true)
+        );
+        assert!(serde_json::from_str::<Base24Bytes>("[1, 2]").is_err());
+    }
+
+    #[test]
+    fn test_encoded() {
+        let encoded =
+            crate::encode_owned(&[0x88, 0x55, 0x33, 0x11]).expect("error during test encode");
+
+        assert_eq!(&*encoded, "5YEATXA");
+        assert_eq!(encoded.len(), 7);
+        assert_eq!(AsRef::<[u8]>::as_ref(&encoded), b"5YEATXA");
+        assert_eq!(AsRef::<str>::as_ref(&encoded), "5YEATXA");
+        assert_eq!(crate::decode(&encoded), Ok(vec![0x88, 0x55, 0x33, 0x11]));
+        assert_eq!(format!("[{:>9}]", encoded), "[  5YEATXA]");
+        assert_eq!(encoded.clone().into_string(), "5YEATXA");
+
+        assert_eq!(
+            crate::encode_owned(&[1, 2, 3]),
+            Err(Base24Error::EncodeInputLengthInvalid { len: 3 })
+        );
+    }
+}
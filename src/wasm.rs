@@ -0,0 +1,42 @@
+//! JavaScript bindings for encoding and decoding.
+//!
+//! Build with `cargo rustc --release --target wasm32-unknown-unknown --features wasm
+//! --crate-type cdylib` and generate the JavaScript glue with `wasm-bindgen`. Errors are
+//! thrown as exceptions carrying the message of the [`Base24Error`].
+
+use crate::errors::Base24Error;
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use wasm_bindgen::prelude::*;
+
+/// Encodes `data`, whose length must be a multiple of 4.
+#[wasm_bindgen]
+pub fn encode(data: &[u8]) -> Result<String, JsValue> {
+    crate::encode(data).map_err(to_js)
+}
+
+/// Decodes `data`, whose length must be a multiple of 7 chars.
+#[wasm_bindgen]
+pub fn decode(data: &str) -> Result<Vec<u8>, JsValue> {
+    crate::decode(data).map_err(to_js)
+}
+
+fn to_js(err: Base24Error) -> JsValue {
+    JsValue::from_str(&err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Creating a `JsValue` needs a JavaScript host, so only the success paths run natively
+    #[test]
+    fn test_wasm() {
+        let data = [0xFF, 0xFF, 0xFF, 0xFF, 0x88, 0x55, 0x33, 0x11];
+
+        assert_eq!(encode(&data).ok().as_deref(), Some("X5GGBH75YEATXA"));
+        assert_eq!(decode("x5ggbh75yeatxa").ok(), Some(data.to_vec()));
+    }
+}